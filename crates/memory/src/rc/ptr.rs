@@ -53,6 +53,13 @@ impl<T: ?Sized> Ptr<T> {
     pub fn ref_count(this: &Self) -> usize {
         Rc::strong_count(&this.0)
     }
+
+    /// Returns a mutable reference into the value, if there are no other references to it
+    ///
+    /// See also: [std::rc::Rc::get_mut]
+    pub fn get_mut(this: &mut Self) -> Option<&mut T> {
+        Rc::get_mut(&mut this.0)
+    }
 }
 
 impl<T: Clone> Ptr<T> {
@@ -92,6 +92,38 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("append", |ctx| {
+        let expected_error = "an iterable and a value";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [value]) => {
+                let iterable = iterable.clone();
+                let value = value.clone();
+                let result = adaptors::Chain::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    KIterator::new(generators::Once::new(value)),
+                );
+
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("buffered", |ctx| {
+        let expected_error = "an iterable and a buffer size greater than zero";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) if *n >= 1 => {
+                let iterable = iterable.clone();
+                let n = *n;
+                let result = adaptors::Buffered::new(ctx.vm.make_iterator(iterable)?, n.into());
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("chain", |ctx| {
         let expected_error = "two iterable values";
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
@@ -109,6 +141,69 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("chain_many", |ctx| {
+        let expected_error = "any number of iterable values";
+
+        // When called as an instance function, the instance is the first iterable in the chain
+        let instance = ctx.instance().filter(|instance| instance.is_iterable());
+        let iterables = instance
+            .into_iter()
+            .chain(ctx.args())
+            .cloned()
+            .collect::<ValueVec>();
+
+        if let Some(unexpected) = iterables.iter().find(|value| !value.is_iterable()) {
+            return type_error(expected_error, unexpected);
+        }
+
+        let iters = iterables
+            .into_iter()
+            .map(|iterable| ctx.vm.make_iterator(iterable))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(KIterator::new(adaptors::ChainMany::new(iters)).into())
+    });
+
+    result.add_fn("chunk_mean", |ctx| {
+        let expected_error = "an iterable and a chunk size greater than zero";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) => {
+                let iterable = iterable.clone();
+                let n = *n;
+                match adaptors::ChunkSum::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    adaptors::ChunkSumMode::Mean,
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.chunk_mean: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("chunk_sum", |ctx| {
+        let expected_error = "an iterable and a chunk size greater than zero";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) => {
+                let iterable = iterable.clone();
+                let n = *n;
+                match adaptors::ChunkSum::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    adaptors::ChunkSumMode::Sum,
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.chunk_sum: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("chunks", |ctx| {
         let expected_error = "an iterable and a chunk size greater than zero";
 
@@ -125,6 +220,69 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("chunks_overlapping", |ctx| {
+        let expected_error =
+            "an iterable, a chunk size greater than zero, and an overlap less than the chunk size";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n), KValue::Number(overlap)]) if *overlap >= 0.0 => {
+                let iterable = iterable.clone();
+                let (n, overlap) = (*n, *overlap);
+                match adaptors::ChunksOverlapping::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    overlap.into(),
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.chunks_overlapping: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("collect_result", |ctx| {
+        let expected_error = "an iterable and an error predicate function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [predicate]) if predicate.is_callable() => {
+                let iterable = iterable.clone();
+                let predicate = predicate.clone();
+                let iterator = ctx.vm.make_iterator(iterable)?;
+                let (size_hint, _) = iterator.size_hint();
+                let mut result = ValueVec::with_capacity(size_hint);
+
+                for output in iterator.map(collect_pair) {
+                    match output {
+                        Output::Value(value) => {
+                            match ctx
+                                .vm
+                                .run_function(predicate.clone(), CallArgs::Single(value.clone()))
+                            {
+                                // The predicate has marked the value as an error,
+                                // so stop collecting and return it directly
+                                Ok(KValue::Bool(true)) => return Ok(value),
+                                Ok(KValue::Bool(false)) => result.push(value),
+                                Ok(unexpected) => {
+                                    return type_error(
+                                        "a Bool to be returned from the predicate",
+                                        &unexpected,
+                                    )
+                                }
+                                Err(error) => return Err(error),
+                            }
+                        }
+                        Output::Error(error) => return Err(error),
+                        _ => unreachable!(),
+                    }
+                }
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("consume", |ctx| {
         let expected_error = "an iterable value (and optional consumer function)";
 
@@ -177,6 +335,120 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("counts_by", |ctx| {
+        let expected_error = "an iterable, and an optional key function";
+
+        let (iterable, key_fn) = match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => (iterable.clone(), None),
+            (iterable, [f]) if f.is_callable() => (iterable.clone(), Some(f.clone())),
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        let mut counts = ValueMap::default();
+
+        for output in ctx.vm.make_iterator(iterable)?.map(collect_pair) {
+            let value = match output {
+                Output::Value(value) => value,
+                Output::Error(error) => return Err(error),
+                _ => unreachable!(),
+            };
+
+            let key = match &key_fn {
+                Some(f) => ctx.vm.run_function(f.clone(), CallArgs::Single(value))?,
+                None => value,
+            };
+
+            let key = match ValueKey::try_from(key.clone()) {
+                Ok(key) => key,
+                Err(_) => {
+                    return runtime_error!(
+                        "iterator.counts_by: Expected a hashable key, found '{}'",
+                        key.type_as_string()
+                    )
+                }
+            };
+
+            match counts.get_mut(&key) {
+                Some(KValue::Number(count)) => *count = *count + KNumber::I64(1),
+                _ => {
+                    counts.insert(key, 1.into());
+                }
+            }
+        }
+
+        Ok(KMap::with_data(counts).into())
+    });
+
+    result.add_fn("count_where", |ctx| {
+        let expected_error = "an iterable and predicate function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [predicate]) if predicate.is_callable() => {
+                let iterable = iterable.clone();
+                let predicate = predicate.clone();
+                let mut result = 0;
+
+                for output in ctx.vm.make_iterator(iterable)?.map(collect_pair) {
+                    let value = match output {
+                        Output::Value(value) => value,
+                        Output::Error(error) => return Err(error),
+                        _ => unreachable!(),
+                    };
+
+                    match ctx
+                        .vm
+                        .run_function(predicate.clone(), CallArgs::Single(value))?
+                    {
+                        KValue::Bool(true) => result += 1,
+                        KValue::Bool(false) => {}
+                        unexpected => {
+                            return type_error(
+                                "a Bool to be returned from the predicate",
+                                &unexpected,
+                            )
+                        }
+                    }
+                }
+
+                Ok(KValue::Number(result.into()))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("dedup", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let result =
+                    adaptors::Dedup::new(ctx.vm.make_iterator(iterable)?, ctx.vm.spawn_shared_vm());
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("dedup_map", |ctx| {
+        let expected_error = "an iterable and function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let f = f.clone();
+                let result = adaptors::DedupMap::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                );
+
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("each", |ctx| {
         let expected_error = "an iterable and function";
 
@@ -196,6 +468,19 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("cumulative_counts", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let result = adaptors::CumulativeCounts::new(ctx.vm.make_iterator(iterable)?);
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("cycle", |ctx| {
         let expected_error = "an iterable";
 
@@ -223,6 +508,65 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("enumerate_filter", |ctx| {
+        let expected_error = "an iterable and a predicate function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [predicate]) if predicate.is_callable() => {
+                let iterable = iterable.clone();
+                let predicate = predicate.clone();
+                let result = adaptors::EnumerateFilter::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    predicate,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("enumerate_with_total", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let result = adaptors::EnumerateWithTotal::new(ctx.vm.make_iterator(iterable)?);
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("exactly_one", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let mut iter = ctx.vm.make_iterator(iterable)?.map(collect_pair);
+
+                let result = match iter.next() {
+                    Some(Output::Value(value)) => value,
+                    Some(Output::Error(error)) => return Err(error),
+                    None => return Ok(KValue::Null),
+                    _ => unreachable!(),
+                };
+
+                match iter.next() {
+                    Some(Output::Value(_)) => {
+                        runtime_error!("iterator.exactly_one: Expected a single value, found more")
+                    }
+                    Some(Output::Error(error)) => Err(error),
+                    None => Ok(result),
+                    _ => unreachable!(),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("find", |ctx| {
         let expected_error = "an iterable and a predicate function";
 
@@ -263,18 +607,148 @@ pub fn make_module() -> KMap {
         }
     });
 
-    result.add_fn("flatten", |ctx| {
-        let expected_error = "an iterable";
+    result.add_fn("find_indices", |ctx| {
+        let expected_error = "an iterable and a predicate function";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, []) => {
+            (iterable, [predicate]) if predicate.is_callable() => {
                 let iterable = iterable.clone();
-                let result = adaptors::Flatten::new(
-                    ctx.vm.make_iterator(iterable)?,
-                    ctx.vm.spawn_shared_vm(),
-                );
+                let predicate = predicate.clone();
+                let mut result = ValueVec::new();
 
-                Ok(KIterator::new(result).into())
+                for (i, output) in ctx.vm.make_iterator(iterable)?.enumerate() {
+                    let predicate_result = match output {
+                        Output::Value(value) => ctx
+                            .vm
+                            .run_function(predicate.clone(), CallArgs::Single(value)),
+                        Output::ValuePair(a, b) => ctx
+                            .vm
+                            .run_function(predicate.clone(), CallArgs::AsTuple(&[a, b])),
+                        Output::Error(error) => return Err(error),
+                    };
+
+                    match predicate_result? {
+                        KValue::Bool(true) => result.push(i.into()),
+                        KValue::Bool(false) => {}
+                        unexpected => {
+                            return type_error(
+                                "a Bool to be returned from the predicate",
+                                &unexpected,
+                            )
+                        }
+                    }
+                }
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("first_duplicate", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let mut seen = ValueMap::default();
+
+                for output in ctx.vm.make_iterator(iterable)?.map(collect_pair) {
+                    match output {
+                        Output::Value(value) => {
+                            let key = match ValueKey::try_from(value.clone()) {
+                                Ok(key) => key,
+                                Err(_) => {
+                                    return runtime_error!(
+                                        "iterator.first_duplicate: Expected a hashable value, found '{}'",
+                                        value.type_as_string()
+                                    )
+                                }
+                            };
+                            if seen.insert(key, KValue::Null).is_some() {
+                                return Ok(value);
+                            }
+                        }
+                        Output::Error(error) => return Err(error),
+                        _ => unreachable!(),
+                    }
+                }
+
+                Ok(KValue::Null)
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("flat_map", |ctx| {
+        let expected_error = "an iterable and function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let f = f.clone();
+                let result = adaptors::FlatMap::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                );
+
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("flat_map_indexed", |ctx| {
+        let expected_error = "an iterable and function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let f = f.clone();
+                let result = adaptors::FlatMapIndexed::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                );
+
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("flat_map_pairs", |ctx| {
+        let expected_error = "an iterable and function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let f = f.clone();
+                let result = adaptors::FlatMapPairs::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                );
+
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("flatten", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let result = adaptors::Flatten::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    ctx.vm.spawn_shared_vm(),
+                );
+
+                Ok(KIterator::new(result).into())
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
@@ -323,6 +797,57 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("fold_while", |ctx| {
+        let expected_error = "an iterable, initial value, and folding function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [result, f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let mut fold_result = result.clone();
+                let f = f.clone();
+
+                for output in ctx.vm.make_iterator(iterable)?.map(collect_pair) {
+                    match output {
+                        Output::Value(value) => {
+                            match ctx.vm.run_function(
+                                f.clone(),
+                                CallArgs::Separate(&[fold_result, value]),
+                            )? {
+                                KValue::Tuple(t) if t.len() == 2 => match (&t[0], &t[1]) {
+                                    (KValue::Bool(done), result) => {
+                                        fold_result = result.clone();
+                                        if *done {
+                                            break;
+                                        }
+                                    }
+                                    (unexpected, _) => {
+                                        return type_error(
+                                            "a Bool as the first value in the (done, result) \
+                                             tuple returned from the folding function",
+                                            unexpected,
+                                        )
+                                    }
+                                },
+                                unexpected => {
+                                    return type_error(
+                                        "a (Bool, Value) tuple to be returned from the \
+                                         folding function",
+                                        &unexpected,
+                                    )
+                                }
+                            }
+                        }
+                        Output::Error(error) => return Err(error),
+                        _ => unreachable!(),
+                    }
+                }
+
+                Ok(fold_result)
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("generate", |ctx| match ctx.args() {
         [f] if f.is_callable() => {
             let result = generators::Generate::new(f.clone(), ctx.vm.spawn_shared_vm());
@@ -335,6 +860,46 @@ pub fn make_module() -> KMap {
         unexpected => type_error_with_slice("(Function), or (Number, Function)", unexpected),
     });
 
+    result.add_fn("group_adjacent_sum", |ctx| {
+        let expected_error = "an iterable, a key function, and a combining function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [key_fn, combine_fn])
+                if key_fn.is_callable() && combine_fn.is_callable() =>
+            {
+                let iterable = iterable.clone();
+                let key_fn = key_fn.clone();
+                let combine_fn = combine_fn.clone();
+                let result = adaptors::GroupAdjacentSum::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    key_fn,
+                    combine_fn,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("group_by", |ctx| {
+        let expected_error = "an iterable and a key function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [key_fn]) if key_fn.is_callable() => {
+                let iterable = iterable.clone();
+                let key_fn = key_fn.clone();
+                let result = adaptors::GroupBy::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    key_fn,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("intersperse", |ctx| {
         let expected_error = "an iterable and a separator";
 
@@ -361,6 +926,22 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("intersperse_after", |ctx| {
+        let expected_error = "an iterable and a separator";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [separator]) => {
+                let iterable = iterable.clone();
+                let separator = separator.clone();
+                let result =
+                    adaptors::IntersperseAfter::new(ctx.vm.make_iterator(iterable)?, separator);
+
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("iter", |ctx| {
         let expected_error = "an iterable";
 
@@ -431,6 +1012,25 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("max_n", |ctx| {
+        let expected_error = "an iterable, a non-negative Number, and an optional key function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) if *n >= 0 => {
+                let iterable = iterable.clone();
+                let n = n.into();
+                run_extremes_n(ctx.vm, iterable, n, None, InvertResult::Yes)
+            }
+            (iterable, [KValue::Number(n), key_fn]) if *n >= 0 && key_fn.is_callable() => {
+                let iterable = iterable.clone();
+                let n = n.into();
+                let key_fn = key_fn.clone();
+                run_extremes_n(ctx.vm, iterable, n, Some(key_fn), InvertResult::Yes)
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("min", |ctx| {
         let expected_error = "an iterable and an optional key function";
 
@@ -449,72 +1049,40 @@ pub fn make_module() -> KMap {
     });
 
     result.add_fn("min_max", |ctx| {
-        let expected_error = "an iterable and an optional key function";
-
-        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, []) => {
-                let iterable = iterable.clone();
-                let mut result = None;
+        let (iterable, key_fn, with_indices) = {
+            let expected_error =
+                "an iterable, an optional key function, and an optional 'with indices' Bool";
 
-                for iter_output in ctx.vm.make_iterator(iterable)?.map(collect_pair) {
-                    match iter_output {
-                        Output::Value(value) => {
-                            result = Some(match result {
-                                Some((min, max)) => (
-                                    compare_values(ctx.vm, min, value.clone(), InvertResult::No)?,
-                                    compare_values(ctx.vm, max, value, InvertResult::Yes)?,
-                                ),
-                                None => (value.clone(), value),
-                            })
-                        }
-                        Output::Error(error) => return Err(error),
-                        _ => unreachable!(),
-                    }
+            match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+                (iterable, []) => (iterable.clone(), None, false),
+                (iterable, [KValue::Bool(with_indices)]) => (iterable.clone(), None, *with_indices),
+                (iterable, [key_fn]) if key_fn.is_callable() => {
+                    (iterable.clone(), Some(key_fn.clone()), false)
                 }
+                (iterable, [key_fn, KValue::Bool(with_indices)]) if key_fn.is_callable() => {
+                    (iterable.clone(), Some(key_fn.clone()), *with_indices)
+                }
+                (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+            }
+        };
+
+        run_min_max(ctx.vm, iterable, key_fn, with_indices)
+    });
 
-                Ok(result.map_or(KValue::Null, |(min, max)| {
-                    KValue::Tuple(vec![min, max].into())
-                }))
+    result.add_fn("min_n", |ctx| {
+        let expected_error = "an iterable, a non-negative Number, and an optional key function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) if *n >= 0 => {
+                let iterable = iterable.clone();
+                let n = n.into();
+                run_extremes_n(ctx.vm, iterable, n, None, InvertResult::No)
             }
-            (iterable, [key_fn]) if key_fn.is_callable() => {
+            (iterable, [KValue::Number(n), key_fn]) if *n >= 0 && key_fn.is_callable() => {
                 let iterable = iterable.clone();
+                let n = n.into();
                 let key_fn = key_fn.clone();
-                let mut result = None;
-
-                for iter_output in ctx.vm.make_iterator(iterable)?.map(collect_pair) {
-                    match iter_output {
-                        Output::Value(value) => {
-                            let key = ctx
-                                .vm
-                                .run_function(key_fn.clone(), CallArgs::Single(value.clone()))?;
-                            let value_and_key = (value, key);
-
-                            result = Some(match result {
-                                Some((min_and_key, max_and_key)) => (
-                                    compare_values_with_key(
-                                        ctx.vm,
-                                        min_and_key,
-                                        value_and_key.clone(),
-                                        InvertResult::No,
-                                    )?,
-                                    compare_values_with_key(
-                                        ctx.vm,
-                                        max_and_key,
-                                        value_and_key,
-                                        InvertResult::Yes,
-                                    )?,
-                                ),
-                                None => (value_and_key.clone(), value_and_key),
-                            })
-                        }
-                        Output::Error(error) => return Err(error),
-                        _ => unreachable!(), // value pairs have been collected in collect_pair
-                    }
-                }
-
-                Ok(result.map_or(KValue::Null, |((min, _), (max, _))| {
-                    KValue::Tuple(vec![min, max].into())
-                }))
+                run_extremes_n(ctx.vm, iterable, n, Some(key_fn), InvertResult::No)
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
@@ -550,6 +1118,66 @@ pub fn make_module() -> KMap {
         unexpected => type_error_with_slice("a single value", unexpected),
     });
 
+    result.add_fn("pairwise_map", |ctx| {
+        let expected_error = "an iterable and a function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let f = f.clone();
+                let result = adaptors::PairwiseMap::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("partition", |ctx| {
+        let expected_error = "an iterable and predicate function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [predicate]) if predicate.is_callable() => {
+                let iterable = iterable.clone();
+                let predicate = predicate.clone();
+                let mut matched = ValueVec::new();
+                let mut unmatched = ValueVec::new();
+
+                for output in ctx.vm.make_iterator(iterable)?.map(collect_pair) {
+                    let value = match output {
+                        Output::Value(value) => value,
+                        Output::Error(error) => return Err(error),
+                        _ => unreachable!(),
+                    };
+
+                    match ctx
+                        .vm
+                        .run_function(predicate.clone(), CallArgs::Single(value.clone()))?
+                    {
+                        KValue::Bool(true) => matched.push(value),
+                        KValue::Bool(false) => unmatched.push(value),
+                        unexpected => {
+                            return type_error(
+                                "a Bool to be returned from the predicate",
+                                &unexpected,
+                            )
+                        }
+                    }
+                }
+
+                let result = vec![
+                    KList::with_data(matched).into(),
+                    KList::with_data(unmatched).into(),
+                ];
+                Ok(KValue::Tuple(result.into()))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("peekable", |ctx| {
         let expected_error = "an iterable";
 
@@ -605,6 +1233,24 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("prepend", |ctx| {
+        let expected_error = "an iterable and a value";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [value]) => {
+                let iterable = iterable.clone();
+                let value = value.clone();
+                let result = adaptors::Chain::new(
+                    KIterator::new(generators::Once::new(value)),
+                    ctx.vm.make_iterator(iterable)?,
+                );
+
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("product", |ctx| {
         let (iterable, initial_value) = {
             let expected_error = "an iterable and optional initial value";
@@ -628,112 +1274,609 @@ pub fn make_module() -> KMap {
             let result = generators::RepeatN::new(value.clone(), n.into());
             Ok(KIterator::new(result).into())
         }
-        unexpected => type_error_with_slice("(Value), or (Number, Value)", unexpected),
+        unexpected => type_error_with_slice("(Value), or (Number, Value)", unexpected),
+    });
+
+    result.add_fn("reversed", |ctx| {
+        let expected_error = "an iterable and non-negative number";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                match adaptors::Reversed::new(ctx.vm.make_iterator(iterable)?) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.reversed: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("scan", |ctx| {
+        let expected_error = "an iterable, an initial state, and a function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [initial_state, f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let initial_state = initial_state.clone();
+                let f = f.clone();
+                let result = adaptors::Scan::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    initial_state,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("scan_map", |ctx| {
+        let expected_error = "an iterable, an initial state, and a function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [initial_state, f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let initial_state = initial_state.clone();
+                let f = f.clone();
+                let result = adaptors::ScanMap::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    initial_state,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("scan_until", |ctx| {
+        let expected_error =
+            "an iterable, an initial state, a function, and a predicate to check the state";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [initial_state, f, predicate])
+                if f.is_callable() && predicate.is_callable() =>
+            {
+                let iterable = iterable.clone();
+                let initial_state = initial_state.clone();
+                let f = f.clone();
+                let predicate = predicate.clone();
+                let result = adaptors::ScanUntil::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    initial_state,
+                    f,
+                    predicate,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("skip", |ctx| {
+        let expected_error = "an iterable and non-negative number";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) if *n >= 0.0 => {
+                let iterable = iterable.clone();
+                let n = *n;
+                let mut iter = ctx.vm.make_iterator(iterable)?;
+
+                for _ in 0..n.into() {
+                    if let Some(Output::Error(error)) = iter.next() {
+                        return Err(error);
+                    }
+                }
+
+                Ok(KValue::Iterator(iter))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("sliding_reduce", |ctx| {
+        let expected_error =
+            "an iterable, a window size greater than zero, an initial state, and a function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n), initial_state, f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let n = *n;
+                let initial_state = initial_state.clone();
+                let f = f.clone();
+                match adaptors::SlidingReduce::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    initial_state,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.sliding_reduce: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("split_at", |ctx| {
+        let expected_error = "an iterable and a non-negative Number";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) if *n >= 0 => {
+                let iterable = iterable.clone();
+                let n: usize = n.into();
+                let mut iter = ctx.vm.make_iterator(iterable)?;
+                let mut prefix = ValueVec::new();
+
+                while prefix.len() < n {
+                    match iter.next().map(collect_pair) {
+                        Some(Output::Value(value)) => prefix.push(value),
+                        Some(Output::Error(error)) => return Err(error),
+                        Some(_) => unreachable!(),
+                        None => break,
+                    }
+                }
+
+                let result = vec![KList::with_data(prefix).into(), iter.into()];
+                Ok(KValue::Tuple(result.into()))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("step", |ctx| {
+        let expected_error = "an iterable and positive step size";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) if *n > 0 => {
+                let iterable = iterable.clone();
+                let step_size = n.into();
+                match adaptors::Step::new(ctx.vm.make_iterator(iterable)?, step_size) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.step: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("step_windows", |ctx| {
+        let expected_error =
+            "an iterable, a window size greater than zero, and a stride greater than zero";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(size), KValue::Number(stride)])
+                if *size >= 0 && *stride >= 0 =>
+            {
+                let iterable = iterable.clone();
+                let (size, stride) = (*size, *stride);
+                match adaptors::StepWindows::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    size.into(),
+                    stride.into(),
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.step_windows: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("sum", |ctx| {
+        let (iterable, initial_value) = {
+            let expected_error = "an iterable and optional initial value";
+
+            match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+                (iterable, []) => (iterable.clone(), KValue::Number(0.into())),
+                (iterable, [initial_value]) => (iterable.clone(), initial_value.clone()),
+                (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+            }
+        };
+
+        fold_with_operator(ctx.vm, iterable, initial_value, BinaryOp::Add)
+    });
+
+    result.add_fn("take", |ctx| {
+        let expected_error = "an iterable and a count or predicate";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) if *n >= 0.0 => {
+                let iterable = iterable.clone();
+                let n = *n;
+                let result = adaptors::Take::new(ctx.vm.make_iterator(iterable)?, n.into());
+                Ok(KIterator::new(result).into())
+            }
+            (iterable, [predicate]) if predicate.is_callable() => {
+                let iterable = iterable.clone();
+                let predicate = predicate.clone();
+                let result = adaptors::TakeWhile::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    predicate,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("take_until", |ctx| {
+        let expected_error = "an iterable and a predicate";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [predicate]) if predicate.is_callable() => {
+                let iterable = iterable.clone();
+                let predicate = predicate.clone();
+                let result = adaptors::TakeUntil::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    predicate,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("to_list", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let iterator = ctx.vm.make_iterator(iterable)?;
+                let (size_hint, _) = iterator.size_hint();
+                let mut result = ValueVec::with_capacity(size_hint);
+
+                for output in iterator.map(collect_pair) {
+                    match output {
+                        Output::Value(value) => result.push(value),
+                        Output::Error(error) => return Err(error),
+                        _ => unreachable!(),
+                    }
+                }
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("to_map", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let iterator = ctx.vm.make_iterator(iterable)?;
+                let (size_hint, _) = iterator.size_hint();
+                let mut result = ValueMap::with_capacity(size_hint);
+
+                for output in iterator {
+                    let (key, value) = match output {
+                        Output::ValuePair(key, value) => (key, value),
+                        Output::Value(KValue::Tuple(t)) if t.len() == 2 => {
+                            let key = t[0].clone();
+                            let value = t[1].clone();
+                            (key, value)
+                        }
+                        Output::Value(value) => (value, KValue::Null),
+                        Output::Error(error) => return Err(error),
+                    };
+
+                    result.insert(ValueKey::try_from(key)?, value);
+                }
+
+                Ok(KValue::Map(KMap::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("to_map_with", |ctx| {
+        let expected_error = "an iterable and a merge function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [f]) if f.is_callable() => {
+                let iterable = iterable.clone();
+                let f = f.clone();
+                let iterator = ctx.vm.make_iterator(iterable)?;
+                let (size_hint, _) = iterator.size_hint();
+                let mut result = ValueMap::with_capacity(size_hint);
+
+                for output in iterator {
+                    let (key, value) = match output {
+                        Output::ValuePair(key, value) => (key, value),
+                        Output::Value(KValue::Tuple(t)) if t.len() == 2 => {
+                            let key = t[0].clone();
+                            let value = t[1].clone();
+                            (key, value)
+                        }
+                        Output::Value(value) => (value, KValue::Null),
+                        Output::Error(error) => return Err(error),
+                    };
+
+                    match result.get_mut(&ValueKey::try_from(key.clone())?) {
+                        Some(existing) => {
+                            *existing = ctx.vm.run_function(
+                                f.clone(),
+                                CallArgs::Separate(&[existing.clone(), value]),
+                            )?;
+                        }
+                        None => {
+                            result.insert(ValueKey::try_from(key)?, value);
+                        }
+                    }
+                }
+
+                Ok(KValue::Map(KMap::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("to_string", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let iterator = ctx.vm.make_iterator(iterable)?;
+                let (size_hint, _) = iterator.size_hint();
+                let mut display_context = DisplayContext::with_vm_and_capacity(ctx.vm, size_hint);
+                for output in iterator.map(collect_pair) {
+                    match output {
+                        Output::Value(KValue::Str(s)) => display_context.append(s),
+                        Output::Value(value) => value.display(&mut display_context)?,
+                        Output::Error(error) => return Err(error),
+                        _ => unreachable!(),
+                    };
+                }
+
+                Ok(display_context.result().into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("to_tuple", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let iterator = ctx.vm.make_iterator(iterable)?;
+                let (size_hint, _) = iterator.size_hint();
+                let mut result = Vec::with_capacity(size_hint);
+
+                for output in iterator.map(collect_pair) {
+                    match output {
+                        Output::Value(value) => result.push(value),
+                        Output::Error(error) => return Err(error),
+                        _ => unreachable!(),
+                    }
+                }
+
+                Ok(KValue::Tuple(result.into()))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("unique", |ctx| {
+        let expected_error = "an iterable";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, []) => {
+                let iterable = iterable.clone();
+                let result = adaptors::Unique::new(ctx.vm.make_iterator(iterable)?);
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("windows", |ctx| {
+        let expected_error = "an iterable and a chunnk size greater than zero";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) => {
+                let iterable = iterable.clone();
+                let n = *n;
+                match adaptors::Windows::new(ctx.vm.make_iterator(iterable)?, n.into()) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.windows: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("windows_all", |ctx| {
+        let expected_error =
+            "an iterable, a window size greater than zero, and a predicate function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n), predicate]) if predicate.is_callable() => {
+                let iterable = iterable.clone();
+                let n = *n;
+                let predicate = predicate.clone();
+                match adaptors::Windows::new(ctx.vm.make_iterator(iterable)?, n.into()) {
+                    Ok(windows) => run_windows_predicate(ctx.vm, windows, predicate, true),
+                    Err(e) => runtime_error!("iterator.windows_all: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("windows_any", |ctx| {
+        let expected_error =
+            "an iterable, a window size greater than zero, and a predicate function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n), predicate]) if predicate.is_callable() => {
+                let iterable = iterable.clone();
+                let n = *n;
+                let predicate = predicate.clone();
+                match adaptors::Windows::new(ctx.vm.make_iterator(iterable)?, n.into()) {
+                    Ok(windows) => run_windows_predicate(ctx.vm, windows, predicate, false),
+                    Err(e) => runtime_error!("iterator.windows_any: {}", e),
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
     });
 
-    result.add_fn("reversed", |ctx| {
-        let expected_error = "an iterable and non-negative number";
+    result.add_fn("windows_apply", |ctx| {
+        let expected_error = "an iterable, a window size greater than zero, and a function";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, []) => {
+            (iterable, [KValue::Number(n), f]) if f.is_callable() => {
                 let iterable = iterable.clone();
-                match adaptors::Reversed::new(ctx.vm.make_iterator(iterable)?) {
+                let n = *n;
+                let f = f.clone();
+                match adaptors::WindowsApply::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                ) {
                     Ok(result) => Ok(KIterator::new(result).into()),
-                    Err(e) => runtime_error!("iterator.reversed: {}", e),
+                    Err(e) => runtime_error!("iterator.windows_apply: {}", e),
                 }
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
     });
 
-    result.add_fn("skip", |ctx| {
-        let expected_error = "an iterable and non-negative number";
+    result.add_fn("windows_argmax", |ctx| {
+        let expected_error = "an iterable and a window size greater than zero";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, [KValue::Number(n)]) if *n >= 0.0 => {
+            (iterable, [KValue::Number(n)]) => {
                 let iterable = iterable.clone();
                 let n = *n;
-                let mut iter = ctx.vm.make_iterator(iterable)?;
-
-                for _ in 0..n.into() {
-                    if let Some(Output::Error(error)) = iter.next() {
-                        return Err(error);
-                    }
+                match adaptors::WindowsArgMax::max(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    ctx.vm.spawn_shared_vm(),
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.windows_argmax: {}", e),
                 }
-
-                Ok(KValue::Iterator(iter))
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
     });
 
-    result.add_fn("step", |ctx| {
-        let expected_error = "an iterable and positive step size";
+    result.add_fn("windows_argmin", |ctx| {
+        let expected_error = "an iterable and a window size greater than zero";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, [KValue::Number(n)]) if *n > 0 => {
+            (iterable, [KValue::Number(n)]) => {
                 let iterable = iterable.clone();
-                let step_size = n.into();
-                match adaptors::Step::new(ctx.vm.make_iterator(iterable)?, step_size) {
+                let n = *n;
+                match adaptors::WindowsArgMax::min(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    ctx.vm.spawn_shared_vm(),
+                ) {
                     Ok(result) => Ok(KIterator::new(result).into()),
-                    Err(e) => runtime_error!("iterator.step: {}", e),
+                    Err(e) => runtime_error!("iterator.windows_argmin: {}", e),
                 }
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
     });
 
-    result.add_fn("sum", |ctx| {
-        let (iterable, initial_value) = {
-            let expected_error = "an iterable and optional initial value";
+    result.add_fn("windows_dedup", |ctx| {
+        let expected_error = "an iterable and a window size greater than zero";
 
-            match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-                (iterable, []) => (iterable.clone(), KValue::Number(0.into())),
-                (iterable, [initial_value]) => (iterable.clone(), initial_value.clone()),
-                (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n)]) => {
+                let iterable = iterable.clone();
+                let n = *n;
+                match adaptors::WindowsDedup::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    ctx.vm.spawn_shared_vm(),
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.windows_dedup: {}", e),
+                }
             }
-        };
-
-        fold_with_operator(ctx.vm, iterable, initial_value, BinaryOp::Add)
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
     });
 
-    result.add_fn("take", |ctx| {
-        let expected_error = "an iterable and a count or predicate";
+    result.add_fn("windows_diff", |ctx| {
+        let expected_error = "an iterable and a window size greater than zero";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, [KValue::Number(n)]) if *n >= 0.0 => {
+            (iterable, [KValue::Number(n)]) => {
                 let iterable = iterable.clone();
                 let n = *n;
-                let result = adaptors::Take::new(ctx.vm.make_iterator(iterable)?, n.into());
-                Ok(KIterator::new(result).into())
+                match adaptors::WindowsDiff::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    ctx.vm.spawn_shared_vm(),
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.windows_diff: {}", e),
+                }
             }
-            (iterable, [predicate]) if predicate.is_callable() => {
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("windows_join", |ctx| {
+        let expected_error = "an iterable, a window size greater than zero, and a separator String";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable, [KValue::Number(n), KValue::Str(separator)]) => {
                 let iterable = iterable.clone();
-                let predicate = predicate.clone();
-                let result = adaptors::TakeWhile::new(
+                let n = *n;
+                let separator = separator.clone();
+                match adaptors::WindowsJoin::new(
                     ctx.vm.make_iterator(iterable)?,
-                    predicate,
+                    n.into(),
+                    separator,
                     ctx.vm.spawn_shared_vm(),
-                );
-                Ok(KIterator::new(result).into())
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.windows_join: {}", e),
+                }
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
     });
 
-    result.add_fn("to_list", |ctx| {
-        let expected_error = "an iterable";
+    result.add_fn("windows_list", |ctx| {
+        let expected_error = "an iterable and a window size greater than zero";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, []) => {
+            (iterable, [KValue::Number(n)]) => {
                 let iterable = iterable.clone();
-                let iterator = ctx.vm.make_iterator(iterable)?;
-                let (size_hint, _) = iterator.size_hint();
-                let mut result = ValueVec::with_capacity(size_hint);
+                let n = *n;
+                let windows =
+                    match adaptors::Windows::new(ctx.vm.make_iterator(iterable)?, n.into()) {
+                        Ok(windows) => windows,
+                        Err(e) => return runtime_error!("iterator.windows_list: {}", e),
+                    };
 
-                for output in iterator.map(collect_pair) {
+                let mut result = ValueVec::with_capacity(windows.size_hint().0);
+                for output in windows {
                     match output {
-                        Output::Value(value) => result.push(value),
+                        Output::Value(KValue::Tuple(window)) => {
+                            result.push(KList::from_slice(&window).into())
+                        }
                         Output::Error(error) => return Err(error),
                         _ => unreachable!(),
                     }
@@ -745,95 +1888,95 @@ pub fn make_module() -> KMap {
         }
     });
 
-    result.add_fn("to_map", |ctx| {
-        let expected_error = "an iterable";
+    result.add_fn("windows_map_to_map", |ctx| {
+        let expected_error = "an iterable, a window size greater than zero, and a function";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, []) => {
+            (iterable, [KValue::Number(n), f]) if f.is_callable() => {
                 let iterable = iterable.clone();
-                let iterator = ctx.vm.make_iterator(iterable)?;
-                let (size_hint, _) = iterator.size_hint();
-                let mut result = ValueMap::with_capacity(size_hint);
+                let n = *n;
+                let f = f.clone();
+                let windows =
+                    match adaptors::Windows::new(ctx.vm.make_iterator(iterable)?, n.into()) {
+                        Ok(windows) => windows,
+                        Err(e) => return runtime_error!("iterator.windows_map_to_map: {}", e),
+                    };
 
-                for output in iterator {
-                    let (key, value) = match output {
-                        Output::ValuePair(key, value) => (key, value),
-                        Output::Value(KValue::Tuple(t)) if t.len() == 2 => {
-                            let key = t[0].clone();
-                            let value = t[1].clone();
-                            (key, value)
+                let mut result = ValueMap::with_capacity(windows.size_hint().0);
+                for (start, output) in windows.enumerate() {
+                    match output {
+                        Output::Value(window) => {
+                            let value = ctx.vm.run_function(f.clone(), CallArgs::Single(window))?;
+                            result.insert(start.into(), value);
                         }
-                        Output::Value(value) => (value, KValue::Null),
                         Output::Error(error) => return Err(error),
-                    };
-
-                    result.insert(ValueKey::try_from(key)?, value);
+                        _ => unreachable!(),
+                    }
                 }
 
-                Ok(KValue::Map(KMap::with_data(result)))
+                Ok(KMap::with_data(result).into())
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
     });
 
-    result.add_fn("to_string", |ctx| {
-        let expected_error = "an iterable";
+    result.add_fn("windows_reduce", |ctx| {
+        let expected_error =
+            "an iterable, a window size greater than zero, an initial value, and a function";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, []) => {
+            (iterable, [KValue::Number(n), carry, f]) if f.is_callable() => {
                 let iterable = iterable.clone();
-                let iterator = ctx.vm.make_iterator(iterable)?;
-                let (size_hint, _) = iterator.size_hint();
-                let mut display_context = DisplayContext::with_vm_and_capacity(ctx.vm, size_hint);
-                for output in iterator.map(collect_pair) {
-                    match output {
-                        Output::Value(KValue::Str(s)) => display_context.append(s),
-                        Output::Value(value) => value.display(&mut display_context)?,
-                        Output::Error(error) => return Err(error),
-                        _ => unreachable!(),
-                    };
+                let n = *n;
+                let carry = carry.clone();
+                let f = f.clone();
+                match adaptors::WindowsReduce::new(
+                    ctx.vm.make_iterator(iterable)?,
+                    n.into(),
+                    carry,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                ) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.windows_reduce: {}", e),
                 }
-
-                Ok(display_context.result().into())
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
     });
 
-    result.add_fn("to_tuple", |ctx| {
-        let expected_error = "an iterable";
+    result.add_fn("windows_stddev", |ctx| {
+        let expected_error = "an iterable and a window size greater than zero";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, []) => {
+            (iterable, [KValue::Number(n)]) => {
                 let iterable = iterable.clone();
-                let iterator = ctx.vm.make_iterator(iterable)?;
-                let (size_hint, _) = iterator.size_hint();
-                let mut result = Vec::with_capacity(size_hint);
-
-                for output in iterator.map(collect_pair) {
-                    match output {
-                        Output::Value(value) => result.push(value),
-                        Output::Error(error) => return Err(error),
-                        _ => unreachable!(),
-                    }
+                let n = *n;
+                match adaptors::WindowsStddev::new(ctx.vm.make_iterator(iterable)?, n.into()) {
+                    Ok(result) => Ok(KIterator::new(result).into()),
+                    Err(e) => runtime_error!("iterator.windows_stddev: {}", e),
                 }
-
-                Ok(KValue::Tuple(result.into()))
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
     });
 
-    result.add_fn("windows", |ctx| {
-        let expected_error = "an iterable and a chunnk size greater than zero";
+    result.add_fn("windows_zip", |ctx| {
+        let expected_error = "an iterable, a window size greater than zero, and an iterable";
 
         match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
-            (iterable, [KValue::Number(n)]) => {
-                let iterable = iterable.clone();
+            (iterable_a, [KValue::Number(n), iterable_b]) if iterable_b.is_iterable() => {
+                let iterable_a = iterable_a.clone();
+                let iterable_b = iterable_b.clone();
                 let n = *n;
-                match adaptors::Windows::new(ctx.vm.make_iterator(iterable)?, n.into()) {
-                    Ok(result) => Ok(KIterator::new(result).into()),
-                    Err(e) => runtime_error!("iterator.windows: {}", e),
+                let windows_a = adaptors::Windows::new(ctx.vm.make_iterator(iterable_a)?, n.into());
+                let windows_b = adaptors::Windows::new(ctx.vm.make_iterator(iterable_b)?, n.into());
+                match windows_a.and_then(|a| windows_b.map(|b| (a, b))) {
+                    Ok((a, b)) => {
+                        let result = adaptors::Zip::new(KIterator::new(a), KIterator::new(b));
+                        Ok(KIterator::new(result).into())
+                    }
+                    Err(e) => runtime_error!("iterator.windows_zip: {}", e),
                 }
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
@@ -857,6 +2000,47 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("zip3", |ctx| {
+        let expected_error = "three iterables";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable_a, [iterable_b, iterable_c])
+                if iterable_b.is_iterable() && iterable_c.is_iterable() =>
+            {
+                let iterable_a = iterable_a.clone();
+                let iterable_b = iterable_b.clone();
+                let iterable_c = iterable_c.clone();
+                let result = adaptors::Zip3::new(
+                    ctx.vm.make_iterator(iterable_a)?,
+                    ctx.vm.make_iterator(iterable_b)?,
+                    ctx.vm.make_iterator(iterable_c)?,
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("zip_with", |ctx| {
+        let expected_error = "two iterables and a function";
+
+        match ctx.instance_and_args(KValue::is_iterable, expected_error)? {
+            (iterable_a, [iterable_b, f]) if iterable_b.is_iterable() && f.is_callable() => {
+                let iterable_a = iterable_a.clone();
+                let iterable_b = iterable_b.clone();
+                let f = f.clone();
+                let result = adaptors::ZipWith::new(
+                    ctx.vm.make_iterator(iterable_a)?,
+                    ctx.vm.make_iterator(iterable_b)?,
+                    f,
+                    ctx.vm.spawn_shared_vm(),
+                );
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result
 }
 
@@ -899,6 +2083,38 @@ fn fold_with_operator(
     Ok(result)
 }
 
+// Calls the predicate with each window, stopping early when the result is known
+//
+// When `all` is true, then the result is true if all windows pass the predicate,
+// otherwise the result is true if any window passes the predicate.
+fn run_windows_predicate(
+    vm: &mut KotoVm,
+    windows: adaptors::Windows,
+    predicate: KValue,
+    all: bool,
+) -> Result<KValue> {
+    for output in windows {
+        let window = match output {
+            Output::Value(window) => window,
+            Output::Error(error) => return Err(error),
+            _ => unreachable!(),
+        };
+
+        match vm.run_function(predicate.clone(), CallArgs::Single(window))? {
+            KValue::Bool(result) => {
+                if result != all {
+                    return Ok(result.into());
+                }
+            }
+            unexpected => {
+                return type_error("a Bool to be returned from the predicate", &unexpected)
+            }
+        }
+    }
+
+    Ok(all.into())
+}
+
 fn run_iterator_comparison(
     vm: &mut KotoVm,
     iterable: KValue,
@@ -930,6 +2146,8 @@ fn run_iterator_comparison_by_key(
     key_fn: KValue,
     invert_result: InvertResult,
 ) -> Result<KValue> {
+    // The current result's key is cached alongside the result,
+    // so that the key function only gets called once per value.
     let mut result_and_key: Option<(KValue, KValue)> = None;
 
     for iter_output in vm.make_iterator(iterable)?.map(collect_pair) {
@@ -953,6 +2171,117 @@ fn run_iterator_comparison_by_key(
     Ok(result_and_key.map_or(KValue::Null, |(value, _)| value))
 }
 
+// Collects the N smallest (or largest, when inverted) values in a sorted list
+//
+// A sorted buffer of at most N values is maintained along with their keys, so that the key
+// function only gets called once per value.
+fn run_extremes_n(
+    vm: &mut KotoVm,
+    iterable: KValue,
+    n: usize,
+    key_fn: Option<KValue>,
+    invert_result: InvertResult,
+) -> Result<KValue> {
+    use super::value_sort::compare_values;
+    use std::cmp::Ordering;
+
+    let iterator = vm.make_iterator(iterable)?;
+    // N can be much larger than the number of values, so the capacity is capped by the size hint
+    let mut result: Vec<(KValue, KValue)> = Vec::with_capacity(n.min(iterator.size_hint().0));
+
+    if n > 0 {
+        for iter_output in iterator.map(collect_pair) {
+            let value = match iter_output {
+                Output::Value(value) => value,
+                Output::Error(error) => return Err(error),
+                _ => unreachable!(),
+            };
+
+            let key = match &key_fn {
+                Some(f) => vm.run_function(f.clone(), CallArgs::Single(value.clone()))?,
+                None => value.clone(),
+            };
+
+            // Returns true if the new key should be placed before the existing key
+            let mut comes_before = |existing: &KValue| -> Result<bool> {
+                let ordering = compare_values(vm, &key, existing)?;
+                Ok(match invert_result {
+                    InvertResult::Yes => ordering == Ordering::Greater,
+                    InvertResult::No => ordering == Ordering::Less,
+                })
+            };
+
+            if result.len() == n && !comes_before(&result[n - 1].1)? {
+                continue;
+            }
+
+            // Binary search for the insertion position, placing the new value after any values
+            // with equal keys.
+            let (mut low, mut high) = (0, result.len());
+            while low < high {
+                let mid = (low + high) / 2;
+                if comes_before(&result[mid].1)? {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+
+            result.insert(low, (value, key));
+            result.truncate(n);
+        }
+    }
+
+    Ok(KList::with_data(result.into_iter().map(|(value, _)| value).collect()).into())
+}
+
+fn run_min_max(
+    vm: &mut KotoVm,
+    iterable: KValue,
+    key_fn: Option<KValue>,
+    with_indices: bool,
+) -> Result<KValue> {
+    // Each candidate is made up of the value and its index, along with the comparison key
+    let mut result = None;
+
+    for (index, iter_output) in vm.make_iterator(iterable)?.map(collect_pair).enumerate() {
+        match iter_output {
+            Output::Value(value) => {
+                let key = match &key_fn {
+                    Some(key_fn) => {
+                        vm.run_function(key_fn.clone(), CallArgs::Single(value.clone()))?
+                    }
+                    None => value.clone(),
+                };
+                let candidate = ((value, index), key);
+
+                result = Some(match result {
+                    Some((min, max)) => (
+                        compare_values_with_key(vm, min, candidate.clone(), InvertResult::No)?,
+                        compare_values_with_key(vm, max, candidate, InvertResult::Yes)?,
+                    ),
+                    None => (candidate.clone(), candidate),
+                })
+            }
+            Output::Error(error) => return Err(error),
+            _ => unreachable!(), // value pairs have been collected in collect_pair
+        }
+    }
+
+    Ok(result.map_or(
+        KValue::Null,
+        |(((min, min_index), _), ((max, max_index), _))| {
+            let min_max = KValue::Tuple(vec![min, max].into());
+            if with_indices {
+                let indices = KValue::Tuple(vec![min_index.into(), max_index.into()].into());
+                KValue::Tuple(vec![min_max, indices].into())
+            } else {
+                min_max
+            }
+        },
+    ))
+}
+
 // Compares two values using BinaryOp::Less
 //
 // Returns the lesser of the two values, unless `invert_result` is set to Yes
@@ -979,15 +2308,15 @@ fn compare_values(
     }
 }
 
-// Compares two values using BinaryOp::Less
+// Compares the keys of two values using BinaryOp::Less
 //
-// Returns the lesser of the two values, unless `invert_result` is set to Yes
-fn compare_values_with_key(
+// Returns the value and key with the lesser key, unless `invert_result` is set to Yes
+fn compare_values_with_key<T>(
     vm: &mut KotoVm,
-    a_and_key: (KValue, KValue),
-    b_and_key: (KValue, KValue),
+    a_and_key: (T, KValue),
+    b_and_key: (T, KValue),
     invert_result: InvertResult,
-) -> Result<(KValue, KValue)> {
+) -> Result<(T, KValue)> {
     use InvertResult::*;
     use KValue::Bool;
 
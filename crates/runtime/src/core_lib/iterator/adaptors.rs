@@ -1,10 +1,76 @@
 //! Adapators used by the `iterator` core library module
 
 use super::collect_pair;
-use crate::{prelude::*, Error, KIteratorOutput as Output, KotoVm, Result};
-use std::{collections::VecDeque, result::Result as StdResult};
+use crate::{
+    core_lib::value_sort::compare_values, prelude::*, Error, KIteratorOutput as Output, KotoVm,
+    Result,
+};
+use smallvec::smallvec;
+use std::{
+    cmp::Ordering,
+    collections::{HashSet, VecDeque},
+    hash::BuildHasherDefault,
+    result::Result as StdResult,
+};
 use thiserror::Error;
 
+/// An iterator that eagerly pulls values from the adapted iterator into a bounded buffer
+///
+/// Errors are returned as soon as they're encountered while filling the buffer, ahead of any
+/// buffered values.
+pub struct Buffered {
+    iter: KIterator,
+    buffer: VecDeque<Output>,
+    capacity: usize,
+}
+
+impl Buffered {
+    /// Creates a new [Buffered] adaptor
+    pub fn new(iter: KIterator, capacity: usize) -> Self {
+        Self {
+            iter,
+            buffer: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+}
+
+impl KotoIterator for Buffered {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            buffer: self.buffer.clone(),
+            capacity: self.capacity,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for Buffered {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.len() < self.capacity {
+            match self.iter.next() {
+                Some(error @ Output::Error(_)) => return Some(error),
+                Some(output) => self.buffer.push_back(output),
+                None => break,
+            }
+        }
+
+        self.buffer.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let buffered = self.buffer.len();
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
 /// An iterator that links the output of two iterators together in a chained sequence
 pub struct Chain {
     iter_a: Option<KIterator>,
@@ -69,6 +135,157 @@ impl Iterator for Chain {
     }
 }
 
+/// An iterator that links the output of any number of iterators together in a chained sequence
+pub struct ChainMany {
+    iters: VecDeque<KIterator>,
+}
+
+impl ChainMany {
+    /// Creates a [ChainMany] adaptor from a sequence of iterators
+    pub fn new(iters: impl IntoIterator<Item = KIterator>) -> Self {
+        Self {
+            iters: iters.into_iter().collect(),
+        }
+    }
+}
+
+impl KotoIterator for ChainMany {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iters: self
+                .iters
+                .iter()
+                .map(|iter| iter.make_copy())
+                .collect::<Result<_>>()?,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for ChainMany {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(iter) = self.iters.front_mut() {
+            match iter.next() {
+                Some(Output::Error(error)) => {
+                    // Stop iterating after the first error
+                    self.iters.clear();
+                    return Some(Output::Error(error));
+                }
+                output @ Some(_) => return output,
+                None => {
+                    self.iters.pop_front();
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iters
+            .iter()
+            .fold((0, Some(0)), |(lower, upper), iter| {
+                let (iter_lower, iter_upper) = iter.size_hint();
+                let upper = match (upper, iter_upper) {
+                    (Some(a), Some(b)) => a.checked_add(b),
+                    _ => None,
+                };
+                (lower.saturating_add(iter_lower), upper)
+            })
+    }
+}
+
+/// An iterator that yields the sum or mean of each size-N chunk of numbers
+///
+/// The final chunk may contain fewer than N values.
+pub struct ChunkSum {
+    chunks: KIterator,
+    mode: ChunkSumMode,
+}
+
+/// The result that's yielded by [ChunkSum] for each chunk
+#[derive(Clone, Copy)]
+pub enum ChunkSumMode {
+    /// The sum of the chunk's values
+    Sum,
+    /// The mean of the chunk's values
+    Mean,
+}
+
+impl ChunkSum {
+    /// Creates a new [ChunkSum] adaptor
+    pub fn new(
+        iter: KIterator,
+        chunk_size: usize,
+        mode: ChunkSumMode,
+    ) -> StdResult<Self, ChunksError> {
+        Ok(Self {
+            chunks: KIterator::new(Chunks::new(iter, chunk_size)?),
+            mode,
+        })
+    }
+
+    fn reduce_chunk(&self, chunk: &[KValue]) -> Result<KValue> {
+        let mut sum = KNumber::I64(0);
+
+        for value in chunk {
+            match value {
+                KValue::Number(n) => sum = sum + *n,
+                unexpected => {
+                    let fn_name = match self.mode {
+                        ChunkSumMode::Sum => "chunk_sum",
+                        ChunkSumMode::Mean => "chunk_mean",
+                    };
+                    return runtime_error!(
+                        "iterator.{fn_name}: Expected a Number, found '{}'",
+                        unexpected.type_as_string()
+                    );
+                }
+            }
+        }
+
+        let result = match self.mode {
+            ChunkSumMode::Sum => sum.into(),
+            ChunkSumMode::Mean => (f64::from(sum) / chunk.len() as f64).into(),
+        };
+
+        Ok(result)
+    }
+}
+
+impl KotoIterator for ChunkSum {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            chunks: self.chunks.make_copy()?,
+            mode: self.mode,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for ChunkSum {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = match self.chunks.next()? {
+            Output::Value(KValue::Tuple(chunk)) => match self.reduce_chunk(&chunk) {
+                Ok(result) => Output::Value(result),
+                Err(error) => Output::Error(error),
+            },
+            error @ Output::Error(_) => error,
+            _ => unreachable!(),
+        };
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
 /// An iterator that splits the incoming iterator into iterators of size N
 pub struct Chunks {
     iter: KIterator,
@@ -145,6 +362,172 @@ pub enum ChunksError {
     ChunkSizeMustBeAtLeastOne,
 }
 
+/// An iterator that splits the adapted iterator into chunks that overlap with the following chunk
+pub struct ChunksOverlapping {
+    iter: KIterator,
+    chunk_size: usize,
+    overlap: usize,
+    // The values from the end of the previous chunk that start the next chunk
+    carried: Vec<KValue>,
+}
+
+impl ChunksOverlapping {
+    /// Creates a [ChunksOverlapping] adapator
+    pub fn new(
+        iter: KIterator,
+        chunk_size: usize,
+        overlap: usize,
+    ) -> StdResult<Self, ChunksOverlappingError> {
+        if chunk_size < 1 {
+            Err(ChunksOverlappingError::ChunkSizeMustBeAtLeastOne)
+        } else if overlap >= chunk_size {
+            Err(ChunksOverlappingError::OverlapMustBeLessThanChunkSize)
+        } else {
+            Ok(Self {
+                iter,
+                chunk_size,
+                overlap,
+                carried: Vec::with_capacity(overlap),
+            })
+        }
+    }
+}
+
+impl KotoIterator for ChunksOverlapping {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            chunk_size: self.chunk_size,
+            overlap: self.overlap,
+            carried: self.carried.clone(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for ChunksOverlapping {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        chunk.append(&mut self.carried);
+        let carried_count = chunk.len();
+
+        for output in self.iter.clone().take(self.chunk_size - carried_count) {
+            match KValue::try_from(output) {
+                Ok(value) => chunk.push(value),
+                Err(error) => return Some(Output::Error(error)),
+            }
+        }
+
+        // Only carried values remain, so the iteration is complete
+        if chunk.len() == carried_count {
+            return None;
+        }
+
+        if chunk.len() == self.chunk_size {
+            self.carried
+                .extend_from_slice(&chunk[self.chunk_size - self.overlap..]);
+        }
+
+        Some(KTuple::from(chunk).into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let step = self.chunk_size - self.overlap;
+        let chunk_count = |remaining: usize| {
+            // Without carried values, the next chunk needs to be filled from the iterator
+            let (first_chunk, remaining) = if self.carried.is_empty() {
+                if remaining == 0 {
+                    return 0;
+                }
+                (1, remaining.saturating_sub(self.chunk_size))
+            } else {
+                (0, remaining)
+            };
+
+            let mut chunk_count = first_chunk + remaining / step;
+            if remaining % step > 0 {
+                chunk_count += 1;
+            }
+            chunk_count
+        };
+
+        let (lower, upper) = self.iter.size_hint();
+        (chunk_count(lower), upper.map(chunk_count))
+    }
+}
+
+/// An error that can be returned by [ChunksOverlapping::new]
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum ChunksOverlappingError {
+    #[error("the chunk size must be at least 1")]
+    ChunkSizeMustBeAtLeastOne,
+    #[error("the overlap must be less than the chunk size")]
+    OverlapMustBeLessThanChunkSize,
+}
+
+/// An iterator that outputs each value along with the number of times it has been seen so far
+pub struct CumulativeCounts {
+    iter: KIterator,
+    counts: ValueMap,
+}
+
+impl CumulativeCounts {
+    /// Creates a new [CumulativeCounts] adaptor
+    pub fn new(iter: KIterator) -> Self {
+        Self {
+            iter,
+            counts: ValueMap::default(),
+        }
+    }
+}
+
+impl KotoIterator for CumulativeCounts {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            counts: self.counts.clone(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for CumulativeCounts {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.iter.next().map(collect_pair)? {
+            Output::Value(value) => value,
+            error @ Output::Error(_) => return Some(error),
+            _ => unreachable!(),
+        };
+
+        let Ok(key) = ValueKey::try_from(value.clone()) else {
+            return Some(Output::Error(
+                format!(
+                    "iterator.cumulative_counts: Expected a hashable value, found '{}'",
+                    value.type_as_string()
+                )
+                .into(),
+            ));
+        };
+
+        let count = match self.counts.get(&key) {
+            Some(KValue::Number(count)) => *count + KNumber::I64(1),
+            _ => KNumber::I64(1),
+        };
+        self.counts.insert(key, count.into());
+
+        Some(Output::ValuePair(value, count.into()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 /// An iterator that cycles through the adapted iterator infinitely
 pub struct Cycle {
     iter: KIterator,
@@ -220,82 +603,232 @@ impl Iterator for Cycle {
     }
 }
 
-/// An iterator that runs a function on each output value from the adapted iterator
-pub struct Each {
+/// An iterator that skips output values that are equal to the previously yielded value
+///
+/// Value pairs are compared as tuples.
+pub struct Dedup {
     iter: KIterator,
-    function: KValue,
+    previous: Option<KValue>,
     vm: KotoVm,
 }
 
-impl Each {
-    /// Creates a new [Each] adaptor
-    pub fn new(iter: KIterator, function: KValue, vm: KotoVm) -> Self {
-        Self { iter, function, vm }
-    }
-
-    fn map_output(&mut self, output: Output) -> Output {
-        let function = self.function.clone();
-        let functor_result = match output {
-            Output::Value(value) => self.vm.run_function(function, CallArgs::Single(value)),
-            Output::ValuePair(a, b) => self.vm.run_function(function, CallArgs::AsTuple(&[a, b])),
-            other => return other,
-        };
-        match functor_result {
-            Ok(result) => Output::Value(result),
-            Err(error) => Output::Error(error),
+impl Dedup {
+    /// Creates a new [Dedup] adaptor
+    pub fn new(iter: KIterator, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            previous: None,
+            vm,
         }
     }
 }
 
-impl KotoIterator for Each {
+impl KotoIterator for Dedup {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
             iter: self.iter.make_copy()?,
-            function: self.function.clone(),
+            previous: self.previous.clone(),
             vm: self.vm.spawn_shared_vm(),
         };
         Ok(KIterator::new(result))
     }
-
-    fn is_bidirectional(&self) -> bool {
-        self.iter.is_bidirectional()
-    }
-
-    fn next_back(&mut self) -> Option<Output> {
-        self.iter.next_back().map(|output| self.map_output(output))
-    }
 }
 
-impl Iterator for Each {
+impl Iterator for Dedup {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(|output| self.map_output(output))
+        for output in &mut self.iter {
+            let value = match collect_pair(output.clone()) {
+                Output::Value(value) => value,
+                error @ Output::Error(_) => return Some(error),
+                _ => unreachable!(),
+            };
+
+            if let Some(previous) = &self.previous {
+                match self
+                    .vm
+                    .run_binary_op(BinaryOp::Equal, previous.clone(), value.clone())
+                {
+                    Ok(KValue::Bool(true)) => continue,
+                    Ok(KValue::Bool(false)) => {}
+                    Ok(unexpected) => {
+                        return Some(Output::Error(
+                            format!(
+                                "iterator.dedup: Expected a Bool from the equality comparison, found '{}'",
+                                unexpected.type_as_string()
+                            )
+                            .into(),
+                        ))
+                    }
+                    Err(error) => return Some(Output::Error(error)),
+                }
+            }
+
+            self.previous = Some(value);
+            return Some(output);
+        }
+
+        None
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        let (_lower, upper) = self.iter.size_hint();
+        (0, upper)
     }
 }
 
-/// An iterator that attaches an enumerated iteration position to each value
-pub struct Enumerate {
+/// An iterator that runs a function on each output value, skipping consecutive equal results
+pub struct DedupMap {
+    // The adapted iterator, wrapped in an [Each] adaptor
     iter: KIterator,
-    index: usize,
+    previous: Option<KValue>,
+    vm: KotoVm,
 }
 
-impl Enumerate {
-    /// Creates a new [Enumerate] adaptor
-    pub fn new(iter: KIterator) -> Self {
-        Self { iter, index: 0 }
+impl DedupMap {
+    /// Creates a new [DedupMap] adaptor
+    pub fn new(iter: KIterator, function: KValue, vm: KotoVm) -> Self {
+        let iter = KIterator::new(Each::new(iter, function, vm.spawn_shared_vm()));
+        Self {
+            iter,
+            previous: None,
+            vm,
+        }
     }
 }
 
-impl KotoIterator for Enumerate {
+impl KotoIterator for DedupMap {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
             iter: self.iter.make_copy()?,
-            index: self.index,
+            previous: self.previous.clone(),
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for DedupMap {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for output in &mut self.iter {
+            let value = match output {
+                Output::Value(value) => value,
+                error @ Output::Error(_) => return Some(error),
+                Output::ValuePair(_, _) => unreachable!(), // Each only outputs single values
+            };
+
+            if let Some(previous) = &self.previous {
+                match self
+                    .vm
+                    .run_binary_op(BinaryOp::Equal, previous.clone(), value.clone())
+                {
+                    Ok(KValue::Bool(true)) => continue,
+                    Ok(KValue::Bool(false)) => {}
+                    Ok(unexpected) => {
+                        return Some(Output::Error(
+                            format!(
+                                "iterator.dedup_map: Expected a Bool from the equality comparison, found '{}'",
+                                unexpected.type_as_string()
+                            )
+                            .into(),
+                        ))
+                    }
+                    Err(error) => return Some(Output::Error(error)),
+                }
+            }
+
+            self.previous = Some(value.clone());
+            return Some(Output::Value(value));
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+/// An iterator that runs a function on each output value from the adapted iterator
+pub struct Each {
+    iter: KIterator,
+    function: KValue,
+    vm: KotoVm,
+}
+
+impl Each {
+    /// Creates a new [Each] adaptor
+    pub fn new(iter: KIterator, function: KValue, vm: KotoVm) -> Self {
+        Self { iter, function, vm }
+    }
+
+    fn map_output(&mut self, output: Output) -> Output {
+        let function = self.function.clone();
+        let functor_result = match output {
+            Output::Value(value) => self.vm.run_function(function, CallArgs::Single(value)),
+            Output::ValuePair(a, b) => self.vm.run_function(function, CallArgs::AsTuple(&[a, b])),
+            other => return other,
+        };
+        match functor_result {
+            Ok(result) => Output::Value(result),
+            Err(error) => Output::Error(error),
+        }
+    }
+}
+
+impl KotoIterator for Each {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            function: self.function.clone(),
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+
+    fn is_bidirectional(&self) -> bool {
+        self.iter.is_bidirectional()
+    }
+
+    fn next_back(&mut self) -> Option<Output> {
+        self.iter.next_back().map(|output| self.map_output(output))
+    }
+}
+
+impl Iterator for Each {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|output| self.map_output(output))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator that attaches an enumerated iteration position to each value
+pub struct Enumerate {
+    iter: KIterator,
+    index: usize,
+}
+
+impl Enumerate {
+    /// Creates a new [Enumerate] adaptor
+    pub fn new(iter: KIterator) -> Self {
+        Self { iter, index: 0 }
+    }
+}
+
+impl KotoIterator for Enumerate {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            index: self.index,
         };
         Ok(KIterator::new(result))
     }
@@ -323,6 +856,139 @@ impl Iterator for Enumerate {
     }
 }
 
+/// An iterator that keeps values that pass a predicate, along with their original positions
+pub struct EnumerateFilter {
+    iter: KIterator,
+    index: usize,
+    predicate: KValue,
+    vm: KotoVm,
+}
+
+impl EnumerateFilter {
+    /// Creates a new [EnumerateFilter] adaptor
+    pub fn new(iter: KIterator, predicate: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            index: 0,
+            predicate,
+            vm,
+        }
+    }
+}
+
+impl KotoIterator for EnumerateFilter {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            index: self.index,
+            predicate: self.predicate.clone(),
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for EnumerateFilter {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(output) = self.iter.next().map(collect_pair) {
+            let index = self.index;
+            self.index += 1;
+
+            let value = match output {
+                Output::Value(value) => value,
+                error @ Output::Error(_) => return Some(error),
+                _ => unreachable!(),
+            };
+
+            let result = match self
+                .vm
+                .run_function(self.predicate.clone(), CallArgs::Single(value.clone()))
+            {
+                Ok(KValue::Bool(false)) => continue,
+                Ok(KValue::Bool(true)) => Output::ValuePair(index.into(), value),
+                Ok(unexpected) => Output::Error(
+                    format!(
+                        "iterator.enumerate_filter: Expected a Bool to be returned from the predicate, found '{}'",
+                        unexpected.type_as_string()
+                    )
+                    .into(),
+                ),
+                Err(error) => Output::Error(error),
+            };
+
+            return Some(result);
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_lower, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// An iterator that outputs the index of each iterator value, along with the total count
+///
+/// The total is taken from the iterator's size hint when it's exact, otherwise `null` is used.
+pub struct EnumerateWithTotal {
+    iter: KIterator,
+    index: usize,
+    total: KValue,
+}
+
+impl EnumerateWithTotal {
+    /// Creates a new [EnumerateWithTotal] adaptor
+    pub fn new(iter: KIterator) -> Self {
+        let total = match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => lower.into(),
+            _ => KValue::Null,
+        };
+        Self {
+            iter,
+            index: 0,
+            total,
+        }
+    }
+}
+
+impl KotoIterator for EnumerateWithTotal {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            index: self.index,
+            total: self.total.clone(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for EnumerateWithTotal {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self
+            .iter
+            .next()
+            .map(collect_pair)
+            .map(|output| match output {
+                Output::Value(value) => {
+                    let index = self.index.into();
+                    KValue::Tuple(vec![index, self.total.clone(), value].into()).into()
+                }
+                other => other,
+            });
+        self.index += 1;
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 /// An iterator that flattens the output of nested iterators
 pub struct Flatten {
     vm: KotoVm,
@@ -382,505 +1048,2270 @@ impl Iterator for Flatten {
     }
 }
 
-/// An iterator that inserts a separator value between each output value from the adapted iterator
-pub struct Intersperse {
+/// An iterator that calls a function with each output from the adapted iterator, and then
+/// flattens the iterables returned by the function
+pub struct FlatMap {
     iter: KIterator,
-    peeked: Option<Output>,
-    next_is_separator: bool,
-    separator: KValue,
+    function: KValue,
+    vm: KotoVm,
+    nested: Option<KIterator>,
 }
 
-impl Intersperse {
-    /// Creates a new [Intersperse] adaptor
-    pub fn new(iter: KIterator, separator: KValue) -> Self {
+impl FlatMap {
+    /// Creates a new [FlatMap] adaptor
+    pub fn new(iter: KIterator, function: KValue, vm: KotoVm) -> Self {
         Self {
             iter,
-            peeked: None,
-            next_is_separator: false,
-            separator,
+            function,
+            vm,
+            nested: None,
         }
     }
 }
 
-impl KotoIterator for Intersperse {
+impl KotoIterator for FlatMap {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
             iter: self.iter.make_copy()?,
-            peeked: self.peeked.clone(),
-            next_is_separator: self.next_is_separator,
-            separator: self.separator.clone(),
+            function: self.function.clone(),
+            vm: self.vm.spawn_shared_vm(),
+            nested: match &self.nested {
+                Some(nested) => Some(nested.make_copy()?),
+                None => None,
+            },
         };
         Ok(KIterator::new(result))
     }
 }
 
-impl Iterator for Intersperse {
+impl Iterator for FlatMap {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.peeked.take().or_else(|| self.iter.next());
+        loop {
+            if let Some(nested) = &mut self.nested {
+                if let result @ Some(_) = nested.next() {
+                    return result;
+                }
+                self.nested = None;
+            }
 
-        if next.is_some() {
-            let result = if self.next_is_separator {
-                self.peeked = next;
-                Some(Output::Value(self.separator.clone()))
-            } else {
-                next
+            let value = match self.iter.next().map(collect_pair)? {
+                Output::Value(value) => value,
+                error @ Output::Error(_) => return Some(error),
+                Output::ValuePair(_, _) => unreachable!(),
             };
 
-            self.next_is_separator = !self.next_is_separator;
-            result
-        } else {
-            None
+            match self
+                .vm
+                .run_function(self.function.clone(), CallArgs::Single(value))
+            {
+                Ok(iterable) if iterable.is_iterable() => match self.vm.make_iterator(iterable) {
+                    Ok(nested) => self.nested = Some(nested),
+                    Err(error) => return Some(Output::Error(error)),
+                },
+                Ok(unexpected) => {
+                    return Some(Output::Error(
+                        format!(
+                            "iterator.flat_map: Expected an iterable to be returned from the function, found '{}'",
+                            unexpected.type_as_string()
+                        )
+                        .into(),
+                    ))
+                }
+                Err(error) => return Some(Output::Error(error)),
+            }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        intersperse_size_hint(&self.iter, self.next_is_separator)
+        (0, None)
     }
 }
 
-/// An iterator that inserts a separator value between each output value from the adapted iterator
-///
-/// The separator value is the result of calling a provided separator function.
-pub struct IntersperseWith {
+/// An iterator that calls a function with the index and value of each output from the adapted
+/// iterator, and then flattens the iterables returned by the function
+pub struct FlatMapIndexed {
     iter: KIterator,
-    peeked: Option<Output>,
-    next_is_separator: bool,
-    separator_function: KValue,
+    function: KValue,
     vm: KotoVm,
+    index: usize,
+    nested: Option<KIterator>,
 }
 
-impl IntersperseWith {
-    /// Creates a new [IntersperseWith] adaptor
-    pub fn new(iter: KIterator, separator_function: KValue, vm: KotoVm) -> Self {
+impl FlatMapIndexed {
+    /// Creates a new [FlatMapIndexed] adaptor
+    pub fn new(iter: KIterator, function: KValue, vm: KotoVm) -> Self {
         Self {
             iter,
-            peeked: None,
-            next_is_separator: false,
-            separator_function,
+            function,
             vm,
+            index: 0,
+            nested: None,
         }
     }
 }
 
-impl KotoIterator for IntersperseWith {
+impl KotoIterator for FlatMapIndexed {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
             iter: self.iter.make_copy()?,
-            peeked: self.peeked.clone(),
-            next_is_separator: self.next_is_separator,
-            separator_function: self.separator_function.clone(),
+            function: self.function.clone(),
             vm: self.vm.spawn_shared_vm(),
+            index: self.index,
+            nested: match &self.nested {
+                Some(nested) => Some(nested.make_copy()?),
+                None => None,
+            },
         };
         Ok(KIterator::new(result))
     }
 }
 
-impl Iterator for IntersperseWith {
+impl Iterator for FlatMapIndexed {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.peeked.take().or_else(|| self.iter.next());
+        loop {
+            if let Some(nested) = &mut self.nested {
+                if let result @ Some(_) = nested.next() {
+                    return result;
+                }
+                self.nested = None;
+            }
 
-        if next.is_some() {
-            let result = if self.next_is_separator {
-                self.peeked = next;
-                Some(
-                    match self
-                        .vm
-                        .run_function(self.separator_function.clone(), CallArgs::None)
-                    {
-                        Ok(result) => Output::Value(result),
-                        Err(error) => Output::Error(error),
-                    },
-                )
-            } else {
-                next
+            let value = match self.iter.next().map(collect_pair)? {
+                Output::Value(value) => value,
+                error @ Output::Error(_) => return Some(error),
+                Output::ValuePair(_, _) => unreachable!(),
             };
 
-            self.next_is_separator = !self.next_is_separator;
-            result
-        } else {
-            None
-        }
-    }
+            let index = self.index.into();
+            self.index += 1;
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        intersperse_size_hint(&self.iter, self.next_is_separator)
+            match self
+                .vm
+                .run_function(self.function.clone(), CallArgs::Separate(&[index, value]))
+            {
+                Ok(iterable) if iterable.is_iterable() => match self.vm.make_iterator(iterable) {
+                    Ok(nested) => self.nested = Some(nested),
+                    Err(error) => return Some(Output::Error(error)),
+                },
+                Ok(unexpected) => {
+                    return Some(Output::Error(
+                        format!(
+                            "iterator.flat_map_indexed: Expected an iterable to be returned from the function, found '{}'",
+                            unexpected.type_as_string()
+                        )
+                        .into(),
+                    ))
+                }
+                Err(error) => return Some(Output::Error(error)),
+            }
+        }
     }
 }
 
-fn intersperse_size_hint(iter: &KIterator, next_is_separator: bool) -> (usize, Option<usize>) {
-    let (lower, upper) = iter.size_hint();
-    let offset = !next_is_separator as usize;
-
-    let lower = lower.saturating_sub(offset).saturating_add(lower);
-    let upper = upper.and_then(|upper| upper.saturating_sub(offset).checked_add(upper));
-
-    (lower, upper)
-}
-
-/// An iterator that skips over values that fail a predicate, and keeps those that pass
-pub struct Keep {
+/// An iterator that calls a function with each output from the adapted iterator, and then
+/// flattens the returned iterables into key/value pairs
+pub struct FlatMapPairs {
     iter: KIterator,
-    predicate: KValue,
+    function: KValue,
     vm: KotoVm,
+    nested: Option<KIterator>,
 }
 
-impl Keep {
-    /// Creates a new [Keep] adaptor
-    pub fn new(iter: KIterator, predicate: KValue, vm: KotoVm) -> Self {
+impl FlatMapPairs {
+    /// Creates a new [FlatMapPairs] adaptor
+    pub fn new(iter: KIterator, function: KValue, vm: KotoVm) -> Self {
         Self {
             iter,
-            predicate,
+            function,
             vm,
+            nested: None,
         }
     }
 }
 
-impl KotoIterator for Keep {
+impl KotoIterator for FlatMapPairs {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
             iter: self.iter.make_copy()?,
-            predicate: self.predicate.clone(),
+            function: self.function.clone(),
             vm: self.vm.spawn_shared_vm(),
+            nested: match &self.nested {
+                Some(nested) => Some(nested.make_copy()?),
+                None => None,
+            },
         };
         Ok(KIterator::new(result))
     }
 }
 
-impl Iterator for Keep {
+impl Iterator for FlatMapPairs {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        for output in &mut self.iter {
-            let predicate = self.predicate.clone();
-            let predicate_result = match &output {
-                Output::Value(value) => self
-                    .vm
-                    .run_function(predicate, CallArgs::Single(value.clone())),
-                Output::ValuePair(a, b) => self
-                    .vm
-                    .run_function(predicate, CallArgs::AsTuple(&[a.clone(), b.clone()])),
-                error @ Output::Error(_) => return Some(error.clone()),
-            };
+        loop {
+            if let Some(nested) = &mut self.nested {
+                if let Some(output) = nested.next() {
+                    let result = match output {
+                        Output::Value(KValue::Tuple(t)) if t.len() == 2 => {
+                            Output::ValuePair(t[0].clone(), t[1].clone())
+                        }
+                        Output::Value(unexpected) => Output::Error(
+                            format!(
+                                "iterator.flat_map_pairs: Expected a Tuple containing two values, found '{}'",
+                                unexpected.type_as_string()
+                            )
+                            .into(),
+                        ),
+                        other => other,
+                    };
+                    return Some(result);
+                }
+                self.nested = None;
+            }
 
-            let result = match predicate_result {
-                Ok(KValue::Bool(false)) => continue,
-                Ok(KValue::Bool(true)) => output,
-                Ok(unexpected) => Output::Error(
-                    format!(
-                    "iterator.keep: Expected a Bool to be returned from the predicate, found '{}'",
-                    unexpected.type_as_string()
-                )
-                    .into(),
-                ),
-                Err(error) => Output::Error(error),
+            let value = match self.iter.next().map(collect_pair)? {
+                Output::Value(value) => value,
+                error @ Output::Error(_) => return Some(error),
+                Output::ValuePair(_, _) => unreachable!(),
             };
 
-            return Some(result);
+            match self
+                .vm
+                .run_function(self.function.clone(), CallArgs::Single(value))
+            {
+                Ok(iterable) if iterable.is_iterable() => match self.vm.make_iterator(iterable) {
+                    Ok(nested) => self.nested = Some(nested),
+                    Err(error) => return Some(Output::Error(error)),
+                },
+                Ok(unexpected) => {
+                    return Some(Output::Error(
+                        format!(
+                            "iterator.flat_map_pairs: Expected an iterable to be returned from the function, found '{}'",
+                            unexpected.type_as_string()
+                        )
+                        .into(),
+                    ))
+                }
+                Err(error) => return Some(Output::Error(error)),
+            }
         }
-
-        None
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let (_lower, upper) = self.iter.size_hint();
-        (0, upper)
     }
 }
 
-/// An iterator that outputs the first element from any ValuePairs
-pub struct PairFirst {
+/// An iterator that merges adjacent values that share the same key using a combining function
+pub struct GroupAdjacentSum {
     iter: KIterator,
+    key_fn: KValue,
+    combine_fn: KValue,
+    vm: KotoVm,
+    // The key and merged value of the group that's currently being accumulated
+    group: Option<(KValue, KValue)>,
 }
 
-impl PairFirst {
-    /// Creates a new [PairFirst] adaptor
-    pub fn new(iter: KIterator) -> Self {
-        Self { iter }
+impl GroupAdjacentSum {
+    /// Creates a new [GroupAdjacentSum] adaptor
+    pub fn new(iter: KIterator, key_fn: KValue, combine_fn: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            key_fn,
+            combine_fn,
+            vm,
+            group: None,
+        }
+    }
+
+    fn keys_are_equal(&mut self, a: &KValue, b: &KValue) -> Result<bool> {
+        match self
+            .vm
+            .run_binary_op(BinaryOp::Equal, a.clone(), b.clone())?
+        {
+            KValue::Bool(result) => Ok(result),
+            unexpected => runtime_error!(
+                "iterator.group_adjacent_sum: Expected a Bool from the key comparison, found '{}'",
+                unexpected.type_as_string()
+            ),
+        }
+    }
+
+    fn next_group(&mut self) -> Result<Option<KValue>> {
+        while let Some(output) = self.iter.next().map(collect_pair) {
+            let value = match output {
+                Output::Value(value) => value,
+                Output::Error(error) => return Err(error),
+                Output::ValuePair(_, _) => unreachable!(),
+            };
+
+            let key = self
+                .vm
+                .run_function(self.key_fn.clone(), CallArgs::Single(value.clone()))?;
+
+            match self.group.take() {
+                Some((group_key, merged)) => {
+                    if self.keys_are_equal(&group_key, &key)? {
+                        let merged = self.vm.run_function(
+                            self.combine_fn.clone(),
+                            CallArgs::Separate(&[merged, value]),
+                        )?;
+                        self.group = Some((group_key, merged));
+                    } else {
+                        self.group = Some((key, value));
+                        return Ok(Some(merged));
+                    }
+                }
+                None => self.group = Some((key, value)),
+            }
+        }
+
+        Ok(self.group.take().map(|(_, merged)| merged))
     }
 }
 
-impl KotoIterator for PairFirst {
+impl KotoIterator for GroupAdjacentSum {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
             iter: self.iter.make_copy()?,
+            key_fn: self.key_fn.clone(),
+            combine_fn: self.combine_fn.clone(),
+            vm: self.vm.spawn_shared_vm(),
+            group: self.group.clone(),
         };
         Ok(KIterator::new(result))
     }
 }
 
-impl Iterator for PairFirst {
+impl Iterator for GroupAdjacentSum {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.iter.next() {
-            Some(Output::ValuePair(first, _)) => Some(Output::Value(first)),
-            other => other,
+        match self.next_group() {
+            Ok(result) => result.map(Output::Value),
+            Err(error) => Some(Output::Error(error)),
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        let (lower, upper) = self.iter.size_hint();
+        let pending = self.group.is_some() as usize;
+        ((lower + pending).min(1), upper.map(|upper| upper + pending))
     }
 }
 
-/// An iterator that outputs the second element from any ValuePairs
-pub struct PairSecond {
+/// An iterator that collects runs of adjacent values that share the same key into lists
+///
+/// Each group is yielded as a pair containing the key and the list of values.
+pub struct GroupBy {
     iter: KIterator,
+    key_fn: KValue,
+    vm: KotoVm,
+    // The key and values of the group that's currently being accumulated
+    group: Option<(KValue, ValueVec)>,
 }
 
-impl PairSecond {
-    /// Creates a new [PairSecond] adaptor
-    pub fn new(iter: KIterator) -> Self {
-        Self { iter }
+impl GroupBy {
+    /// Creates a new [GroupBy] adaptor
+    pub fn new(iter: KIterator, key_fn: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            key_fn,
+            vm,
+            group: None,
+        }
+    }
+
+    fn keys_are_equal(&mut self, a: &KValue, b: &KValue) -> Result<bool> {
+        match self
+            .vm
+            .run_binary_op(BinaryOp::Equal, a.clone(), b.clone())?
+        {
+            KValue::Bool(result) => Ok(result),
+            unexpected => runtime_error!(
+                "iterator.group_by: Expected a Bool from the key comparison, found '{}'",
+                unexpected.type_as_string()
+            ),
+        }
+    }
+
+    fn next_group(&mut self) -> Result<Option<(KValue, ValueVec)>> {
+        while let Some(output) = self.iter.next().map(collect_pair) {
+            let value = match output {
+                Output::Value(value) => value,
+                Output::Error(error) => return Err(error),
+                Output::ValuePair(_, _) => unreachable!(),
+            };
+
+            let key = self
+                .vm
+                .run_function(self.key_fn.clone(), CallArgs::Single(value.clone()))?;
+
+            match self.group.take() {
+                Some((group_key, mut values)) => {
+                    if self.keys_are_equal(&group_key, &key)? {
+                        values.push(value);
+                        self.group = Some((group_key, values));
+                    } else {
+                        self.group = Some((key, smallvec![value]));
+                        return Ok(Some((group_key, values)));
+                    }
+                }
+                None => self.group = Some((key, smallvec![value])),
+            }
+        }
+
+        Ok(self.group.take())
     }
 }
 
-impl KotoIterator for PairSecond {
+impl KotoIterator for GroupBy {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
             iter: self.iter.make_copy()?,
+            key_fn: self.key_fn.clone(),
+            vm: self.vm.spawn_shared_vm(),
+            group: self.group.clone(),
         };
         Ok(KIterator::new(result))
     }
 }
 
-impl Iterator for PairSecond {
+impl Iterator for GroupBy {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.iter.next() {
-            Some(Output::ValuePair(_, second)) => Some(Output::Value(second)),
-            other => other,
+        match self.next_group() {
+            Ok(result) => {
+                result.map(|(key, values)| Output::ValuePair(key, KList::with_data(values).into()))
+            }
+            Err(error) => Some(Output::Error(error)),
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        let (lower, upper) = self.iter.size_hint();
+        let pending = self.group.is_some() as usize;
+        ((lower + pending).min(1), upper.map(|upper| upper + pending))
     }
 }
 
-/// An iterator adaptor that reverses the output of the input iterator
-pub struct Reversed {
+/// An iterator that inserts a separator value between each output value from the adapted iterator
+pub struct Intersperse {
     iter: KIterator,
+    peeked: Option<Output>,
+    next_is_separator: bool,
+    separator: KValue,
 }
 
-impl Reversed {
-    /// Creates a new [Reversed] adaptor
-    pub fn new(iter: KIterator) -> StdResult<Self, ReversedError> {
-        if iter.is_bidirectional() {
-            Ok(Self {
-                iter: iter.make_copy().map_err(ReversedError::CopyError)?,
-            })
-        } else {
-            Err(ReversedError::IteratorIsntReversible)
+impl Intersperse {
+    /// Creates a new [Intersperse] adaptor
+    pub fn new(iter: KIterator, separator: KValue) -> Self {
+        Self {
+            iter,
+            peeked: None,
+            next_is_separator: false,
+            separator,
         }
     }
 }
 
-impl KotoIterator for Reversed {
+impl KotoIterator for Intersperse {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
             iter: self.iter.make_copy()?,
+            peeked: self.peeked.clone(),
+            next_is_separator: self.next_is_separator,
+            separator: self.separator.clone(),
         };
         Ok(KIterator::new(result))
     }
-
-    fn is_bidirectional(&self) -> bool {
-        true
-    }
-
-    fn next_back(&mut self) -> Option<Output> {
-        self.iter.next()
-    }
 }
 
-impl Iterator for Reversed {
+impl Iterator for Intersperse {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_back()
+        let next = self.peeked.take().or_else(|| self.iter.next());
+
+        if next.is_some() {
+            let result = if self.next_is_separator {
+                self.peeked = next;
+                Some(Output::Value(self.separator.clone()))
+            } else {
+                next
+            };
+
+            self.next_is_separator = !self.next_is_separator;
+            result
+        } else {
+            None
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        intersperse_size_hint(&self.iter, self.next_is_separator)
+    }
+}
+
+/// An iterator that inserts a separator value after each output value from the adapted iterator
+pub struct IntersperseAfter {
+    iter: KIterator,
+    next_is_separator: bool,
+    separator: KValue,
+}
+
+impl IntersperseAfter {
+    /// Creates a new [IntersperseAfter] adaptor
+    pub fn new(iter: KIterator, separator: KValue) -> Self {
+        Self {
+            iter,
+            next_is_separator: false,
+            separator,
+        }
+    }
+}
+
+impl KotoIterator for IntersperseAfter {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            next_is_separator: self.next_is_separator,
+            separator: self.separator.clone(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for IntersperseAfter {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_is_separator {
+            self.next_is_separator = false;
+            return Some(Output::Value(self.separator.clone()));
+        }
+
+        let next = self.iter.next()?;
+        self.next_is_separator = !matches!(next, Output::Error(_));
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let pending = self.next_is_separator as usize;
+
+        (
+            lower.saturating_mul(2).saturating_add(pending),
+            upper.and_then(|upper| upper.checked_mul(2)?.checked_add(pending)),
+        )
+    }
+}
+
+/// An iterator that inserts a separator value between each output value from the adapted iterator
+///
+/// The separator value is the result of calling a provided separator function.
+pub struct IntersperseWith {
+    iter: KIterator,
+    peeked: Option<Output>,
+    next_is_separator: bool,
+    separator_function: KValue,
+    vm: KotoVm,
+}
+
+impl IntersperseWith {
+    /// Creates a new [IntersperseWith] adaptor
+    pub fn new(iter: KIterator, separator_function: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            peeked: None,
+            next_is_separator: false,
+            separator_function,
+            vm,
+        }
+    }
+}
+
+impl KotoIterator for IntersperseWith {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            peeked: self.peeked.clone(),
+            next_is_separator: self.next_is_separator,
+            separator_function: self.separator_function.clone(),
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for IntersperseWith {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.peeked.take().or_else(|| self.iter.next());
+
+        if next.is_some() {
+            let result = if self.next_is_separator {
+                self.peeked = next;
+                Some(
+                    match self
+                        .vm
+                        .run_function(self.separator_function.clone(), CallArgs::None)
+                    {
+                        Ok(result) => Output::Value(result),
+                        Err(error) => Output::Error(error),
+                    },
+                )
+            } else {
+                next
+            };
+
+            self.next_is_separator = !self.next_is_separator;
+            result
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.next_is_separator)
+    }
+}
+
+fn intersperse_size_hint(iter: &KIterator, next_is_separator: bool) -> (usize, Option<usize>) {
+    let (lower, upper) = iter.size_hint();
+    let offset = !next_is_separator as usize;
+
+    let lower = lower.saturating_sub(offset).saturating_add(lower);
+    let upper = upper.and_then(|upper| upper.saturating_sub(offset).checked_add(upper));
+
+    (lower, upper)
+}
+
+/// An iterator that skips over values that fail a predicate, and keeps those that pass
+pub struct Keep {
+    iter: KIterator,
+    predicate: KValue,
+    vm: KotoVm,
+}
+
+impl Keep {
+    /// Creates a new [Keep] adaptor
+    pub fn new(iter: KIterator, predicate: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            predicate,
+            vm,
+        }
+    }
+}
+
+impl KotoIterator for Keep {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            predicate: self.predicate.clone(),
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for Keep {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for output in &mut self.iter {
+            let predicate = self.predicate.clone();
+            let predicate_result = match &output {
+                Output::Value(value) => self
+                    .vm
+                    .run_function(predicate, CallArgs::Single(value.clone())),
+                Output::ValuePair(a, b) => self
+                    .vm
+                    .run_function(predicate, CallArgs::AsTuple(&[a.clone(), b.clone()])),
+                error @ Output::Error(_) => return Some(error.clone()),
+            };
+
+            let result = match predicate_result {
+                Ok(KValue::Bool(false)) => continue,
+                Ok(KValue::Bool(true)) => output,
+                Ok(unexpected) => Output::Error(
+                    format!(
+                    "iterator.keep: Expected a Bool to be returned from the predicate, found '{}'",
+                    unexpected.type_as_string()
+                )
+                    .into(),
+                ),
+                Err(error) => Output::Error(error),
+            };
+
+            return Some(result);
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_lower, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// An iterator that outputs the first element from any ValuePairs
+pub struct PairFirst {
+    iter: KIterator,
+}
+
+impl PairFirst {
+    /// Creates a new [PairFirst] adaptor
+    pub fn new(iter: KIterator) -> Self {
+        Self { iter }
+    }
+}
+
+impl KotoIterator for PairFirst {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for PairFirst {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Output::ValuePair(first, _)) => Some(Output::Value(first)),
+            other => other,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator that outputs the second element from any ValuePairs
+pub struct PairSecond {
+    iter: KIterator,
+}
+
+impl PairSecond {
+    /// Creates a new [PairSecond] adaptor
+    pub fn new(iter: KIterator) -> Self {
+        Self { iter }
+    }
+}
+
+impl KotoIterator for PairSecond {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for PairSecond {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Output::ValuePair(_, second)) => Some(Output::Value(second)),
+            other => other,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator that calls a function with each pair of adjacent values, and yields the results
+pub struct PairwiseMap {
+    iter: KIterator,
+    previous: Option<KValue>,
+    function: KValue,
+    vm: KotoVm,
+}
+
+impl PairwiseMap {
+    /// Creates a new [PairwiseMap] adaptor
+    pub fn new(iter: KIterator, function: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            previous: None,
+            function,
+            vm,
+        }
+    }
+}
+
+impl KotoIterator for PairwiseMap {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            previous: self.previous.clone(),
+            function: self.function.clone(),
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for PairwiseMap {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => match self.iter.next().map(collect_pair)? {
+                Output::Value(value) => value,
+                error @ Output::Error(_) => return Some(error),
+                _ => unreachable!(),
+            },
+        };
+
+        let current = match self.iter.next().map(collect_pair)? {
+            Output::Value(value) => value,
+            error @ Output::Error(_) => return Some(error),
+            _ => unreachable!(),
+        };
+
+        self.previous = Some(current.clone());
+
+        let result = match self.vm.run_function(
+            self.function.clone(),
+            CallArgs::Separate(&[previous, current]),
+        ) {
+            Ok(result) => Output::Value(result),
+            Err(error) => Output::Error(error),
+        };
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.previous.is_some() {
+            (lower, upper)
+        } else {
+            (
+                lower.saturating_sub(1),
+                upper.map(|upper| upper.saturating_sub(1)),
+            )
+        }
+    }
+}
+
+/// An iterator adaptor that reverses the output of the input iterator
+pub struct Reversed {
+    iter: KIterator,
+}
+
+impl Reversed {
+    /// Creates a new [Reversed] adaptor
+    pub fn new(iter: KIterator) -> StdResult<Self, ReversedError> {
+        if iter.is_bidirectional() {
+            Ok(Self {
+                iter: iter.make_copy().map_err(ReversedError::CopyError)?,
+            })
+        } else {
+            Err(ReversedError::IteratorIsntReversible)
+        }
+    }
+}
+
+impl KotoIterator for Reversed {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+        };
+        Ok(KIterator::new(result))
+    }
+
+    fn is_bidirectional(&self) -> bool {
+        true
+    }
+
+    fn next_back(&mut self) -> Option<Output> {
+        self.iter.next()
+    }
+}
+
+impl Iterator for Reversed {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An error that can be returned by [Reversed::new]
+#[allow(missing_docs)]
+#[derive(Debug, Error)]
+pub enum ReversedError {
+    #[error("the provided iterator isn't bidirectional")]
+    IteratorIsntReversible,
+    #[error("failed to copy the iterator ('{0}')")]
+    CopyError(Error),
+}
+
+/// An iterator that threads a state value through a function, yielding each new state
+pub struct Scan {
+    iter: KIterator,
+    state: KValue,
+    function: KValue,
+    vm: KotoVm,
+    finished: bool,
+}
+
+impl Scan {
+    /// Creates a new [Scan] adaptor
+    pub fn new(iter: KIterator, initial_state: KValue, function: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            state: initial_state,
+            function,
+            vm,
+            finished: false,
+        }
+    }
+}
+
+impl KotoIterator for Scan {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            state: self.state.clone(),
+            function: self.function.clone(),
+            vm: self.vm.spawn_shared_vm(),
+            finished: self.finished,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for Scan {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let value = match self.iter.next().map(collect_pair)? {
+            Output::Value(value) => value,
+            error @ Output::Error(_) => return Some(error),
+            _ => unreachable!(),
+        };
+
+        let result = match self.vm.run_function(
+            self.function.clone(),
+            CallArgs::Separate(&[self.state.clone(), value]),
+        ) {
+            Ok(KValue::Null) => {
+                self.finished = true;
+                return None;
+            }
+            Ok(new_state) => {
+                self.state = new_state.clone();
+                Output::Value(new_state)
+            }
+            Err(error) => Output::Error(error),
+        };
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            let (_lower, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+/// An iterator that threads a state value through a function, yielding each new state paired with
+/// the input value
+pub struct ScanMap {
+    iter: KIterator,
+    state: KValue,
+    function: KValue,
+    vm: KotoVm,
+    finished: bool,
+}
+
+impl ScanMap {
+    /// Creates a new [ScanMap] adaptor
+    pub fn new(iter: KIterator, initial_state: KValue, function: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            state: initial_state,
+            function,
+            vm,
+            finished: false,
+        }
+    }
+}
+
+impl KotoIterator for ScanMap {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            state: self.state.clone(),
+            function: self.function.clone(),
+            vm: self.vm.spawn_shared_vm(),
+            finished: self.finished,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for ScanMap {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let value = match self.iter.next().map(collect_pair)? {
+            Output::Value(value) => value,
+            error @ Output::Error(_) => return Some(error),
+            _ => unreachable!(),
+        };
+
+        let result = match self.vm.run_function(
+            self.function.clone(),
+            CallArgs::Separate(&[self.state.clone(), value.clone()]),
+        ) {
+            Ok(KValue::Null) => {
+                self.finished = true;
+                return None;
+            }
+            Ok(new_state) => {
+                self.state = new_state.clone();
+                Output::ValuePair(new_state, value)
+            }
+            Err(error) => Output::Error(error),
+        };
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            let (_lower, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+/// An iterator that threads a state value through a function, yielding each new state until a
+/// predicate returns true for the state
+///
+/// The state that causes the predicate to return true is included in the output.
+pub struct ScanUntil {
+    iter: KIterator,
+    state: KValue,
+    function: KValue,
+    predicate: KValue,
+    vm: KotoVm,
+    finished: bool,
+}
+
+impl ScanUntil {
+    /// Creates a new [ScanUntil] adaptor
+    pub fn new(
+        iter: KIterator,
+        initial_state: KValue,
+        function: KValue,
+        predicate: KValue,
+        vm: KotoVm,
+    ) -> Self {
+        Self {
+            iter,
+            state: initial_state,
+            function,
+            predicate,
+            vm,
+            finished: false,
+        }
+    }
+}
+
+impl KotoIterator for ScanUntil {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            state: self.state.clone(),
+            function: self.function.clone(),
+            predicate: self.predicate.clone(),
+            vm: self.vm.spawn_shared_vm(),
+            finished: self.finished,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for ScanUntil {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let value = match self.iter.next().map(collect_pair)? {
+            Output::Value(value) => value,
+            error @ Output::Error(_) => return Some(error),
+            _ => unreachable!(),
+        };
+
+        let new_state = match self.vm.run_function(
+            self.function.clone(),
+            CallArgs::Separate(&[self.state.clone(), value]),
+        ) {
+            Ok(new_state) => new_state,
+            Err(error) => return Some(Output::Error(error)),
+        };
+
+        let result = match self
+            .vm
+            .run_function(self.predicate.clone(), CallArgs::Single(new_state.clone()))
+        {
+            Ok(KValue::Bool(stop)) => {
+                self.finished = stop;
+                self.state = new_state.clone();
+                Output::Value(new_state)
+            }
+            Ok(unexpected) => Output::Error(
+                format!(
+                    "iterator.scan_until: Expected a Bool to be returned from the predicate, found '{}'",
+                    unexpected.type_as_string()
+                )
+                .into(),
+            ),
+            Err(error) => Output::Error(error),
+        };
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            let (_lower, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+/// An iterator that calls a function for each value with a window of recent values and a state
+///
+/// The window contains up to N of the most recent values, including the current value.
+pub struct SlidingReduce {
+    iter: KIterator,
+    history: VecDeque<KValue>,
+    window_size: usize,
+    state: KValue,
+    function: KValue,
+    vm: KotoVm,
+}
+
+impl SlidingReduce {
+    /// Creates a new [SlidingReduce] adaptor
+    pub fn new(
+        iter: KIterator,
+        window_size: usize,
+        initial_state: KValue,
+        function: KValue,
+        vm: KotoVm,
+    ) -> StdResult<Self, WindowsError> {
+        if window_size < 1 {
+            Err(WindowsError::WindowSizeMustBeAtLeastOne)
+        } else {
+            Ok(Self {
+                iter,
+                history: VecDeque::with_capacity(window_size),
+                window_size,
+                state: initial_state,
+                function,
+                vm,
+            })
+        }
+    }
+}
+
+impl KotoIterator for SlidingReduce {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            history: self.history.clone(),
+            window_size: self.window_size,
+            state: self.state.clone(),
+            function: self.function.clone(),
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for SlidingReduce {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.iter.next().map(collect_pair)? {
+            Output::Value(value) => value,
+            error @ Output::Error(_) => return Some(error),
+            _ => unreachable!(),
+        };
+
+        if self.history.len() == self.window_size {
+            self.history.pop_front();
+        }
+        self.history.push_back(value);
+
+        let window = KTuple::from(self.history.iter().cloned().collect::<Vec<_>>());
+        let result = match self.vm.run_function(
+            self.function.clone(),
+            CallArgs::Separate(&[self.state.clone(), window.into()]),
+        ) {
+            Ok(KValue::Tuple(t)) if t.len() == 2 => {
+                self.state = t[1].clone();
+                Output::Value(t[0].clone())
+            }
+            Ok(unexpected) => Output::Error(
+                format!(
+                    "iterator.sliding_reduce: Expected a Tuple containing two values \
+                     to be returned from the function, found '{}'",
+                    unexpected.type_as_string()
+                )
+                .into(),
+            ),
+            Err(error) => Output::Error(error),
+        };
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
     }
 }
 
-/// An error that can be returned by [Reversed::new]
-#[allow(missing_docs)]
-#[derive(Debug, Error)]
-pub enum ReversedError {
-    #[error("the provided iterator isn't bidirectional")]
-    IteratorIsntReversible,
-    #[error("failed to copy the iterator ('{0}')")]
-    CopyError(Error),
-}
-
 /// An iterator that yields the next value from the input, and then steps forward by
 pub struct Step {
     iter: KIterator,
-    step: u64,
+    step: u64,
+}
+
+impl Step {
+    /// Creates a new [Step] adaptor
+    pub fn new(iter: KIterator, step: u64) -> StdResult<Self, StepError> {
+        if step == 0 {
+            Err(StepError::StepCantBeZero)
+        } else {
+            Ok(Self { iter, step })
+        }
+    }
+}
+
+impl KotoIterator for Step {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            step: self.step,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for Step {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.iter.next();
+        for _ in 0..self.step - 1 {
+            self.iter.next();
+        }
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let step = self.step as usize;
+        let (lower, upper) = self.iter.size_hint();
+        (lower / step, upper.map(|upper| upper / step))
+    }
+}
+
+/// An error that can be returned by [Step::new]
+#[allow(missing_docs)]
+#[derive(Debug, Error)]
+pub enum StepError {
+    #[error("the step size must be greater than zero")]
+    StepCantBeZero,
+}
+
+/// An iterator that yields windows of size N, with each window starting a fixed stride after
+/// the previous window
+///
+/// If the stride is larger than the window size, then the values between windows are skipped.
+pub struct StepWindows {
+    iter: KIterator,
+    cache: VecDeque<KValue>,
+    window_size: usize,
+    stride: usize,
+    started: bool,
+}
+
+impl StepWindows {
+    /// Creates a new [StepWindows] adaptor
+    pub fn new(
+        iter: KIterator,
+        window_size: usize,
+        stride: usize,
+    ) -> StdResult<Self, StepWindowsError> {
+        if window_size < 1 {
+            Err(StepWindowsError::WindowSizeMustBeAtLeastOne)
+        } else if stride < 1 {
+            Err(StepWindowsError::StrideMustBeAtLeastOne)
+        } else {
+            Ok(Self {
+                iter,
+                cache: VecDeque::with_capacity(window_size),
+                window_size,
+                stride,
+                started: false,
+            })
+        }
+    }
+}
+
+impl KotoIterator for StepWindows {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            cache: self.cache.clone(),
+            window_size: self.window_size,
+            stride: self.stride,
+            started: self.started,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for StepWindows {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.started {
+            // Step forward from the start of the previous window,
+            // skipping values in the input if the stride is larger than the window
+            let cached = self.stride.min(self.cache.len());
+            self.cache.drain(..cached);
+            for output in self.iter.clone().take(self.stride - cached) {
+                if let Output::Error(error) = output {
+                    return Some(Output::Error(error));
+                }
+            }
+        }
+        self.started = true;
+
+        while self.cache.len() < self.window_size {
+            let Some(output) = self.iter.next() else {
+                break;
+            };
+
+            match KValue::try_from(output) {
+                Ok(value) => self.cache.push_back(value),
+                Err(error) => return Some(Output::Error(error)),
+            }
+        }
+
+        if self.cache.len() == self.window_size {
+            let result: Vec<_> = self.cache.iter().cloned().collect();
+            Some(KTuple::from(result).into())
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let window_count = |remaining: usize| {
+            let available = self.cache.len() + remaining;
+            if available < self.window_size {
+                return 0;
+            }
+            let following = (available - self.window_size) / self.stride;
+            if self.started {
+                following
+            } else {
+                following + 1
+            }
+        };
+
+        let (lower, upper) = self.iter.size_hint();
+        (window_count(lower), upper.map(window_count))
+    }
+}
+
+/// An error that can be returned by [StepWindows::new]
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum StepWindowsError {
+    #[error("the window size must be at least 1")]
+    WindowSizeMustBeAtLeastOne,
+    #[error("the stride must be at least 1")]
+    StrideMustBeAtLeastOne,
+}
+
+/// An iterator that takes up to N values from the adapted iterator, and then stops
+pub struct Take {
+    iter: KIterator,
+    remaining: usize,
+}
+
+impl Take {
+    /// Creates a new [Take] adaptor
+    pub fn new(iter: KIterator, count: usize) -> Self {
+        Self {
+            iter,
+            remaining: count,
+        }
+    }
+}
+
+impl KotoIterator for Take {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            remaining: self.remaining,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for Take {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            self.iter.next()
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.min(self.remaining),
+            upper.map(|upper| upper.min(self.remaining)),
+        )
+    }
+}
+
+/// An adaptor that yields values from an iterator while they pass a predicate
+pub struct TakeWhile {
+    iter: KIterator,
+    predicate: KValue,
+    vm: KotoVm,
+    finished: bool,
+}
+
+impl TakeWhile {
+    /// Creates a new [Keep] adaptor
+    pub fn new(iter: KIterator, predicate: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            predicate,
+            vm,
+            finished: false,
+        }
+    }
+}
+
+impl KotoIterator for TakeWhile {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            predicate: self.predicate.clone(),
+            vm: self.vm.spawn_shared_vm(),
+            finished: self.finished,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for TakeWhile {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let iter_output = self.iter.next()?;
+        let predicate = self.predicate.clone();
+        let predicate_result = match &iter_output {
+            Output::Value(value) => self
+                .vm
+                .run_function(predicate, CallArgs::Single(value.clone())),
+            Output::ValuePair(a, b) => self
+                .vm
+                .run_function(predicate, CallArgs::AsTuple(&[a.clone(), b.clone()])),
+            error @ Output::Error(_) => return Some(error.clone()),
+        };
+
+        let result = match predicate_result {
+            Ok(KValue::Bool(true)) => iter_output,
+            Ok(KValue::Bool(false)) => {
+                self.finished = true;
+                return None;
+            }
+            Ok(unexpected) => Output::Error(
+                format!(
+                    "expected a Bool to be returned from the predicate, found '{}'",
+                    unexpected.type_as_string()
+                )
+                .into(),
+            ),
+            Err(error) => Output::Error(error),
+        };
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_lower, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// An iterator that outputs values until a value passes the stop predicate
+///
+/// Unlike [TakeWhile], the value that passed the predicate is included in the output.
+pub struct TakeUntil {
+    iter: KIterator,
+    predicate: KValue,
+    vm: KotoVm,
+    finished: bool,
+}
+
+impl TakeUntil {
+    /// Creates a new [TakeUntil] adaptor
+    pub fn new(iter: KIterator, predicate: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter,
+            predicate,
+            vm,
+            finished: false,
+        }
+    }
+}
+
+impl KotoIterator for TakeUntil {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            predicate: self.predicate.clone(),
+            vm: self.vm.spawn_shared_vm(),
+            finished: self.finished,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for TakeUntil {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let iter_output = self.iter.next()?;
+        let predicate = self.predicate.clone();
+        let predicate_result = match &iter_output {
+            Output::Value(value) => self
+                .vm
+                .run_function(predicate, CallArgs::Single(value.clone())),
+            Output::ValuePair(a, b) => self
+                .vm
+                .run_function(predicate, CallArgs::AsTuple(&[a.clone(), b.clone()])),
+            error @ Output::Error(_) => return Some(error.clone()),
+        };
+
+        let result = match predicate_result {
+            Ok(KValue::Bool(false)) => iter_output,
+            Ok(KValue::Bool(true)) => {
+                self.finished = true;
+                iter_output
+            }
+            Ok(unexpected) => Output::Error(
+                format!(
+                    "expected a Bool to be returned from the predicate, found '{}'",
+                    unexpected.type_as_string()
+                )
+                .into(),
+            ),
+            Err(error) => Output::Error(error),
+        };
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            let (lower, upper) = self.iter.size_hint();
+            (lower.min(1), upper)
+        }
+    }
+}
+
+/// An iterator that skips output values that have already been yielded
+///
+/// Value pairs are hashed as tuples.
+pub struct Unique {
+    iter: KIterator,
+    seen: HashSet<ValueKey, BuildHasherDefault<KotoHasher>>,
+}
+
+impl Unique {
+    /// Creates a new [Unique] adaptor
+    pub fn new(iter: KIterator) -> Self {
+        Self {
+            iter,
+            seen: HashSet::default(),
+        }
+    }
+}
+
+impl KotoIterator for Unique {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            seen: self.seen.clone(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for Unique {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for output in &mut self.iter {
+            let value = match collect_pair(output.clone()) {
+                Output::Value(value) => value,
+                error @ Output::Error(_) => return Some(error),
+                _ => unreachable!(),
+            };
+
+            let key = match ValueKey::try_from(value.clone()) {
+                Ok(key) => key,
+                Err(_) => {
+                    return Some(Output::Error(
+                        format!(
+                            "iterator.unique: Expected a hashable value, found '{}'",
+                            value.type_as_string()
+                        )
+                        .into(),
+                    ))
+                }
+            };
+
+            if self.seen.insert(key) {
+                return Some(output);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+/// An iterator that splits the incoming iterator into overlapping iterators of size N
+pub struct Windows {
+    iter: KIterator,
+    cache: VecDeque<KValue>,
+    window_size: usize,
 }
 
-impl Step {
-    /// Creates a new [Step] adaptor
-    pub fn new(iter: KIterator, step: u64) -> StdResult<Self, StepError> {
-        if step == 0 {
-            Err(StepError::StepCantBeZero)
+impl Windows {
+    /// Creates a new [Windows] adaptor
+    pub fn new(iter: KIterator, window_size: usize) -> StdResult<Self, WindowsError> {
+        if window_size < 1 {
+            Err(WindowsError::WindowSizeMustBeAtLeastOne)
         } else {
-            Ok(Self { iter, step })
+            Ok(Self {
+                iter,
+                cache: VecDeque::with_capacity(window_size),
+                window_size,
+            })
+        }
+    }
+}
+
+impl KotoIterator for Windows {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            cache: self.cache.clone(),
+            window_size: self.window_size,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for Windows {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cache.pop_front();
+
+        while self.cache.len() < self.window_size {
+            let Some(output) = self.iter.next() else {
+                break;
+            };
+
+            match KValue::try_from(output) {
+                Ok(value) => self.cache.push_back(value),
+                Err(error) => return Some(Output::Error(error)),
+            }
+        }
+
+        if self.cache.len() == self.window_size {
+            let result: Vec<_> = self.cache.iter().cloned().collect();
+            Some(KTuple::from(result).into())
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let lower = lower.saturating_sub(self.window_size) + 1;
+        let upper = upper.map(|upper| upper.saturating_sub(self.window_size) + 1);
+        (lower, upper)
+    }
+}
+
+/// An error that can be returned by [Windows::new]
+#[derive(Error, Debug)]
+#[allow(missing_docs)]
+pub enum WindowsError {
+    #[error("the window size must be at least 1")]
+    WindowSizeMustBeAtLeastOne,
+}
+
+/// An iterator that calls a function with each sliding window, and yields the results
+///
+/// Unlike [Windows], the tuple that's passed to the function is reused for the following window
+/// when the function doesn't hold on to a reference to it.
+pub struct WindowsApply {
+    iter: KIterator,
+    cache: VecDeque<KValue>,
+    window_size: usize,
+    function: KValue,
+    vm: KotoVm,
+    // The tuple that was passed to the function for the previous window
+    window: Option<KTuple>,
+}
+
+impl WindowsApply {
+    /// Creates a new [WindowsApply] adaptor
+    pub fn new(
+        iter: KIterator,
+        window_size: usize,
+        function: KValue,
+        vm: KotoVm,
+    ) -> StdResult<Self, WindowsError> {
+        if window_size < 1 {
+            Err(WindowsError::WindowSizeMustBeAtLeastOne)
+        } else {
+            Ok(Self {
+                iter,
+                cache: VecDeque::with_capacity(window_size),
+                window_size,
+                function,
+                vm,
+                window: None,
+            })
+        }
+    }
+
+    // Fills the window tuple with the cached values, reusing the previous tuple if possible
+    fn make_window(&mut self) -> KTuple {
+        if let Some(mut window) = self.window.take() {
+            if let Some(data) = window.data_mut() {
+                for (value, cached) in data.iter_mut().zip(self.cache.iter()) {
+                    value.clone_from(cached);
+                }
+                return window;
+            }
+        }
+
+        self.cache.iter().cloned().collect::<Vec<_>>().into()
+    }
+}
+
+impl KotoIterator for WindowsApply {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter: self.iter.make_copy()?,
+            cache: self.cache.clone(),
+            window_size: self.window_size,
+            function: self.function.clone(),
+            vm: self.vm.spawn_shared_vm(),
+            window: None,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for WindowsApply {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cache.pop_front();
+
+        while self.cache.len() < self.window_size {
+            let output = self.iter.next()?;
+
+            match KValue::try_from(output) {
+                Ok(value) => self.cache.push_back(value),
+                Err(error) => return Some(Output::Error(error)),
+            }
+        }
+
+        let window = self.make_window();
+        let result = self.vm.run_function(
+            self.function.clone(),
+            CallArgs::Single(KValue::Tuple(window.clone())),
+        );
+        self.window = Some(window);
+
+        match result {
+            Ok(result) => Some(Output::Value(result)),
+            Err(error) => Some(Output::Error(error)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        // The first cached value is removed before the next window is filled
+        let cached = self.cache.len().saturating_sub(1);
+        let remaining_windows = |remaining: usize| {
+            (remaining.saturating_add(cached) + 1).saturating_sub(self.window_size)
+        };
+        (remaining_windows(lower), upper.map(remaining_windows))
+    }
+}
+
+/// An iterator that yields the index of the largest or smallest value in each sliding window
+///
+/// When a window contains more than one matching value, the index of the first is yielded.
+pub struct WindowsArgMax {
+    windows: KIterator,
+    // The ordering that a value must have compared to the current best value to replace it,
+    // i.e. `Greater` when searching for the max, and `Less` for the min.
+    target: Ordering,
+    vm: KotoVm,
+}
+
+impl WindowsArgMax {
+    /// Creates a new [WindowsArgMax] adaptor that yields the index of each window's max value
+    pub fn max(iter: KIterator, window_size: usize, vm: KotoVm) -> StdResult<Self, WindowsError> {
+        Self::new(iter, window_size, Ordering::Greater, vm)
+    }
+
+    /// Creates a new [WindowsArgMax] adaptor that yields the index of each window's min value
+    pub fn min(iter: KIterator, window_size: usize, vm: KotoVm) -> StdResult<Self, WindowsError> {
+        Self::new(iter, window_size, Ordering::Less, vm)
+    }
+
+    fn new(
+        iter: KIterator,
+        window_size: usize,
+        target: Ordering,
+        vm: KotoVm,
+    ) -> StdResult<Self, WindowsError> {
+        Ok(Self {
+            windows: KIterator::new(Windows::new(iter, window_size)?),
+            target,
+            vm,
+        })
+    }
+
+    fn find_index(&mut self, window: &[KValue]) -> Result<usize> {
+        let mut result = 0;
+
+        for (i, value) in window.iter().enumerate().skip(1) {
+            if compare_values(&mut self.vm, value, &window[result])? == self.target {
+                result = i;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl KotoIterator for WindowsArgMax {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            windows: self.windows.make_copy()?,
+            target: self.target,
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for WindowsArgMax {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = match self.windows.next()? {
+            Output::Value(KValue::Tuple(window)) => match self.find_index(&window) {
+                Ok(index) => Output::Value(index.into()),
+                Err(error) => Output::Error(error),
+            },
+            error @ Output::Error(_) => error,
+            _ => unreachable!(),
+        };
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+}
+
+/// An iterator that yields sliding windows, skipping windows equal to the previous window
+pub struct WindowsDedup {
+    windows: KIterator,
+    previous: Option<KTuple>,
+    vm: KotoVm,
+}
+
+impl WindowsDedup {
+    /// Creates a new [WindowsDedup] adaptor
+    pub fn new(iter: KIterator, window_size: usize, vm: KotoVm) -> StdResult<Self, WindowsError> {
+        Ok(Self {
+            windows: KIterator::new(Windows::new(iter, window_size)?),
+            previous: None,
+            vm,
+        })
+    }
+
+    fn windows_are_equal(&mut self, a: &[KValue], b: &[KValue]) -> Result<bool> {
+        for (a, b) in a.iter().zip(b.iter()) {
+            match self
+                .vm
+                .run_binary_op(BinaryOp::Equal, a.clone(), b.clone())?
+            {
+                KValue::Bool(true) => {}
+                KValue::Bool(false) => return Ok(false),
+                unexpected => {
+                    return runtime_error!(
+                        "iterator.windows_dedup: Expected a Bool from the equality comparison, found '{}'",
+                        unexpected.type_as_string()
+                    )
+                }
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl KotoIterator for WindowsDedup {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            windows: self.windows.make_copy()?,
+            previous: self.previous.clone(),
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for WindowsDedup {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(output) = self.windows.next() {
+            let window = match output {
+                Output::Value(KValue::Tuple(window)) => window,
+                error @ Output::Error(_) => return Some(error),
+                _ => unreachable!(),
+            };
+
+            if let Some(previous) = self.previous.clone() {
+                match self.windows_are_equal(&previous, &window) {
+                    Ok(true) => continue,
+                    Ok(false) => {}
+                    Err(error) => return Some(Output::Error(error)),
+                }
+            }
+
+            self.previous = Some(window.clone());
+            return Some(Output::Value(window.into()));
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.windows.size_hint();
+        (lower.min(1), upper)
+    }
+}
+
+/// An iterator that yields the element-wise differences between adjacent sliding windows
+pub struct WindowsDiff {
+    windows: KIterator,
+    previous: Option<KTuple>,
+    vm: KotoVm,
+}
+
+impl WindowsDiff {
+    /// Creates a new [WindowsDiff] adaptor
+    pub fn new(iter: KIterator, window_size: usize, vm: KotoVm) -> StdResult<Self, WindowsError> {
+        Ok(Self {
+            windows: KIterator::new(Windows::new(iter, window_size)?),
+            previous: None,
+            vm,
+        })
+    }
+
+    fn next_window(&mut self) -> Option<StdResult<KTuple, Error>> {
+        match self.windows.next()? {
+            Output::Value(KValue::Tuple(window)) => Some(Ok(window)),
+            Output::Error(error) => Some(Err(error)),
+            _ => unreachable!(),
         }
     }
+
+    fn diff(&mut self, previous: &[KValue], next: &[KValue]) -> Result<KValue> {
+        let result = next
+            .iter()
+            .zip(previous.iter())
+            .map(|(a, b)| {
+                self.vm
+                    .run_binary_op(BinaryOp::Subtract, a.clone(), b.clone())
+            })
+            .collect::<Result<ValueVec>>()?;
+
+        Ok(KList::with_data(result).into())
+    }
 }
 
-impl KotoIterator for Step {
+impl KotoIterator for WindowsDiff {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
-            iter: self.iter.make_copy()?,
-            step: self.step,
+            windows: self.windows.make_copy()?,
+            previous: self.previous.clone(),
+            vm: self.vm.spawn_shared_vm(),
         };
         Ok(KIterator::new(result))
     }
 }
 
-impl Iterator for Step {
+impl Iterator for WindowsDiff {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let result = self.iter.next();
-        for _ in 0..self.step - 1 {
-            self.iter.next();
-        }
-        result
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => match self.next_window()? {
+                Ok(window) => window,
+                Err(error) => return Some(Output::Error(error)),
+            },
+        };
+
+        let next = match self.next_window()? {
+            Ok(window) => window,
+            Err(error) => return Some(Output::Error(error)),
+        };
+
+        let result = match self.diff(&previous, &next) {
+            Ok(diff) => Output::Value(diff),
+            Err(error) => Output::Error(error),
+        };
+
+        self.previous = Some(next);
+        Some(result)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let step = self.step as usize;
-        let (lower, upper) = self.iter.size_hint();
-        (lower / step, upper.map(|upper| upper / step))
+        let (lower, upper) = self.windows.size_hint();
+        if self.previous.is_some() {
+            (lower, upper)
+        } else {
+            (
+                lower.saturating_sub(1),
+                upper.map(|upper| upper.saturating_sub(1)),
+            )
+        }
     }
 }
 
-/// An error that can be returned by [Step::new]
-#[allow(missing_docs)]
-#[derive(Debug, Error)]
-pub enum StepError {
-    #[error("the step size must be greater than zero")]
-    StepCantBeZero,
+/// An iterator that joins the values in each sliding window into a string
+pub struct WindowsJoin {
+    windows: KIterator,
+    separator: KString,
+    vm: KotoVm,
 }
 
-/// An iterator that takes up to N values from the adapted iterator, and then stops
-pub struct Take {
-    iter: KIterator,
-    remaining: usize,
-}
+impl WindowsJoin {
+    /// Creates a new [WindowsJoin] adaptor
+    pub fn new(
+        iter: KIterator,
+        window_size: usize,
+        separator: KString,
+        vm: KotoVm,
+    ) -> StdResult<Self, WindowsError> {
+        Ok(Self {
+            windows: KIterator::new(Windows::new(iter, window_size)?),
+            separator,
+            vm,
+        })
+    }
 
-impl Take {
-    /// Creates a new [Take] adaptor
-    pub fn new(iter: KIterator, count: usize) -> Self {
-        Self {
-            iter,
-            remaining: count,
+    fn join(&self, window: &[KValue]) -> Result<KValue> {
+        let mut display_context = DisplayContext::with_vm(&self.vm);
+
+        for (i, value) in window.iter().enumerate() {
+            if i > 0 {
+                display_context.append(&self.separator);
+            }
+            match value {
+                KValue::Str(s) => display_context.append(s),
+                _ => value.display(&mut display_context)?,
+            }
         }
+
+        Ok(display_context.result().into())
     }
 }
 
-impl KotoIterator for Take {
+impl KotoIterator for WindowsJoin {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
-            iter: self.iter.make_copy()?,
-            remaining: self.remaining,
+            windows: self.windows.make_copy()?,
+            separator: self.separator.clone(),
+            vm: self.vm.spawn_shared_vm(),
         };
         Ok(KIterator::new(result))
     }
 }
 
-impl Iterator for Take {
+impl Iterator for WindowsJoin {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining > 0 {
-            self.remaining -= 1;
-            self.iter.next()
-        } else {
-            None
-        }
+        let result = match self.windows.next()? {
+            Output::Value(KValue::Tuple(window)) => match self.join(&window) {
+                Ok(joined) => Output::Value(joined),
+                Err(error) => Output::Error(error),
+            },
+            error @ Output::Error(_) => error,
+            _ => unreachable!(),
+        };
+
+        Some(result)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (lower, upper) = self.iter.size_hint();
-        (
-            lower.min(self.remaining),
-            upper.map(|upper| upper.min(self.remaining)),
-        )
+        self.windows.size_hint()
     }
 }
 
-/// An adaptor that yields values from an iterator while they pass a predicate
-pub struct TakeWhile {
-    iter: KIterator,
-    predicate: KValue,
+/// An iterator that reduces sliding windows, carrying state from one window to the next
+pub struct WindowsReduce {
+    windows: KIterator,
+    carry: KValue,
+    function: KValue,
     vm: KotoVm,
-    finished: bool,
 }
 
-impl TakeWhile {
-    /// Creates a new [Keep] adaptor
-    pub fn new(iter: KIterator, predicate: KValue, vm: KotoVm) -> Self {
-        Self {
-            iter,
-            predicate,
+impl WindowsReduce {
+    /// Creates a new [WindowsReduce] adaptor
+    pub fn new(
+        iter: KIterator,
+        window_size: usize,
+        carry: KValue,
+        function: KValue,
+        vm: KotoVm,
+    ) -> StdResult<Self, WindowsError> {
+        Ok(Self {
+            windows: KIterator::new(Windows::new(iter, window_size)?),
+            carry,
+            function,
             vm,
-            finished: false,
-        }
+        })
     }
 }
 
-impl KotoIterator for TakeWhile {
+impl KotoIterator for WindowsReduce {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
-            iter: self.iter.make_copy()?,
-            predicate: self.predicate.clone(),
+            windows: self.windows.make_copy()?,
+            carry: self.carry.clone(),
+            function: self.function.clone(),
             vm: self.vm.spawn_shared_vm(),
-            finished: self.finished,
         };
         Ok(KIterator::new(result))
     }
 }
 
-impl Iterator for TakeWhile {
+impl Iterator for WindowsReduce {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.finished {
-            return None;
-        }
-
-        let iter_output = self.iter.next()?;
-        let predicate = self.predicate.clone();
-        let predicate_result = match &iter_output {
-            Output::Value(value) => self
-                .vm
-                .run_function(predicate, CallArgs::Single(value.clone())),
-            Output::ValuePair(a, b) => self
-                .vm
-                .run_function(predicate, CallArgs::AsTuple(&[a.clone(), b.clone()])),
-            error @ Output::Error(_) => return Some(error.clone()),
+        let window = match self.windows.next()? {
+            Output::Value(window) => window,
+            error @ Output::Error(_) => return Some(error),
+            Output::ValuePair(_, _) => unreachable!(),
         };
 
-        let result = match predicate_result {
-            Ok(KValue::Bool(true)) => iter_output,
-            Ok(KValue::Bool(false)) => {
-                self.finished = true;
-                return None;
+        let result = match self.vm.run_function(
+            self.function.clone(),
+            CallArgs::Separate(&[self.carry.clone(), window]),
+        ) {
+            Ok(KValue::Tuple(t)) if t.len() == 2 => {
+                self.carry = t[1].clone();
+                Output::Value(t[0].clone())
             }
             Ok(unexpected) => Output::Error(
                 format!(
-                    "expected a Bool to be returned from the predicate, found '{}'",
+                    "iterator.windows_reduce: Expected a Tuple containing two values \
+                     to be returned from the function, found '{}'",
                     unexpected.type_as_string()
                 )
                 .into(),
@@ -892,85 +3323,105 @@ impl Iterator for TakeWhile {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (_lower, upper) = self.iter.size_hint();
-        (0, upper)
+        self.windows.size_hint()
     }
 }
 
-/// An iterator that splits the incoming iterator into overlapping iterators of size N
-pub struct Windows {
+/// An iterator that yields the population standard deviation of each sliding window of numbers
+///
+/// Running sums of the window's values and squared values are maintained as the window moves,
+/// so that each window's result is calculated without revisiting the window's values.
+pub struct WindowsStddev {
     iter: KIterator,
-    cache: VecDeque<KValue>,
+    window: VecDeque<f64>,
     window_size: usize,
+    sum: f64,
+    sum_of_squares: f64,
 }
 
-impl Windows {
-    /// Creates a new [Windows] adaptor
+impl WindowsStddev {
+    /// Creates a new [WindowsStddev] adaptor
     pub fn new(iter: KIterator, window_size: usize) -> StdResult<Self, WindowsError> {
         if window_size < 1 {
             Err(WindowsError::WindowSizeMustBeAtLeastOne)
         } else {
             Ok(Self {
                 iter,
-                cache: VecDeque::with_capacity(window_size),
+                window: VecDeque::with_capacity(window_size),
                 window_size,
+                sum: 0.0,
+                sum_of_squares: 0.0,
             })
         }
     }
+
+    fn push_next_value(&mut self) -> Option<Result<()>> {
+        let value = match self.iter.next().map(collect_pair)? {
+            Output::Value(KValue::Number(n)) => f64::from(n),
+            Output::Value(unexpected) => {
+                return Some(runtime_error!(
+                    "iterator.windows_stddev: Expected a Number, found '{}'",
+                    unexpected.type_as_string()
+                ))
+            }
+            Output::Error(error) => return Some(Err(error)),
+            _ => unreachable!(),
+        };
+
+        self.window.push_back(value);
+        self.sum += value;
+        self.sum_of_squares += value * value;
+        Some(Ok(()))
+    }
 }
 
-impl KotoIterator for Windows {
+impl KotoIterator for WindowsStddev {
     fn make_copy(&self) -> Result<KIterator> {
         let result = Self {
             iter: self.iter.make_copy()?,
-            cache: self.cache.clone(),
+            window: self.window.clone(),
             window_size: self.window_size,
+            sum: self.sum,
+            sum_of_squares: self.sum_of_squares,
         };
         Ok(KIterator::new(result))
     }
 }
 
-impl Iterator for Windows {
+impl Iterator for WindowsStddev {
     type Item = Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.cache.pop_front();
-
-        while self.cache.len() < self.window_size {
-            let Some(output) = self.iter.next() else {
-                break;
-            };
-
-            match KValue::try_from(output) {
-                Ok(value) => self.cache.push_back(value),
-                Err(error) => return Some(Output::Error(error)),
+        if self.window.len() == self.window_size {
+            if let Some(removed) = self.window.pop_front() {
+                self.sum -= removed;
+                self.sum_of_squares -= removed * removed;
             }
         }
 
-        if self.cache.len() == self.window_size {
-            let result: Vec<_> = self.cache.iter().cloned().collect();
-            Some(KTuple::from(result).into())
-        } else {
-            None
+        while self.window.len() < self.window_size {
+            if let Err(error) = self.push_next_value()? {
+                return Some(Output::Error(error));
+            }
         }
+
+        let n = self.window_size as f64;
+        let mean = self.sum / n;
+        // Rounding errors in the running sums could produce a slightly negative variance
+        let variance = (self.sum_of_squares / n - mean * mean).max(0.0);
+        Some(Output::Value(variance.sqrt().into()))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (lower, upper) = self.iter.size_hint();
-        let lower = lower.saturating_sub(self.window_size) + 1;
-        let upper = upper.map(|upper| upper.saturating_sub(self.window_size) + 1);
-        (lower, upper)
+        let cached = self.window.len().saturating_sub(1);
+        let remaining_windows = |remaining: usize| {
+            (remaining.saturating_add(cached) + 1).saturating_sub(self.window_size)
+        };
+        (remaining_windows(lower), upper.map(remaining_windows))
     }
 }
 
-/// An error that can be returned by [Windows::new]
-#[derive(Error, Debug)]
-#[allow(missing_docs)]
-pub enum WindowsError {
-    #[error("the window size must be at least 1")]
-    WindowSizeMustBeAtLeastOne,
-}
-
 /// An iterator that combines the output of two iterators, 'zipping' output pairs together
 pub struct Zip {
     iter_a: KIterator,
@@ -1023,4 +3474,136 @@ impl Iterator for Zip {
     }
 }
 
+/// An iterator that combines the output of three iterators into Tuples
+pub struct Zip3 {
+    iter_a: KIterator,
+    iter_b: KIterator,
+    iter_c: KIterator,
+}
+
+impl Zip3 {
+    /// Creates a new [Zip3] adaptor
+    pub fn new(iter_a: KIterator, iter_b: KIterator, iter_c: KIterator) -> Self {
+        Self {
+            iter_a,
+            iter_b,
+            iter_c,
+        }
+    }
+}
+
+impl KotoIterator for Zip3 {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter_a: self.iter_a.make_copy()?,
+            iter_b: self.iter_b.make_copy()?,
+            iter_c: self.iter_c.make_copy()?,
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for Zip3 {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut values = Vec::with_capacity(3);
+
+        for iter in [&mut self.iter_a, &mut self.iter_b, &mut self.iter_c] {
+            match iter.next().map(collect_pair)? {
+                Output::Value(value) => values.push(value),
+                error @ Output::Error(_) => return Some(error),
+                _ => unreachable!(),
+            }
+        }
+
+        Some(Output::Value(KTuple::from(values).into()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_a, upper_a) = self.iter_a.size_hint();
+        let (lower_b, upper_b) = self.iter_b.size_hint();
+        let (lower_c, upper_c) = self.iter_c.size_hint();
+
+        let lower = lower_a.min(lower_b).min(lower_c);
+        let upper = match (upper_a, upper_b, upper_c) {
+            (Some(upper_a), Some(upper_b), Some(upper_c)) => {
+                Some(upper_a.min(upper_b).min(upper_c))
+            }
+            _ => None,
+        };
+
+        (lower, upper)
+    }
+}
+
+/// An iterator that combines the output of two iterators by calling a function with output pairs
+pub struct ZipWith {
+    iter_a: KIterator,
+    iter_b: KIterator,
+    function: KValue,
+    vm: KotoVm,
+}
+
+impl ZipWith {
+    /// Creates a new [ZipWith] adaptor
+    pub fn new(iter_a: KIterator, iter_b: KIterator, function: KValue, vm: KotoVm) -> Self {
+        Self {
+            iter_a,
+            iter_b,
+            function,
+            vm,
+        }
+    }
+}
+
+impl KotoIterator for ZipWith {
+    fn make_copy(&self) -> Result<KIterator> {
+        let result = Self {
+            iter_a: self.iter_a.make_copy()?,
+            iter_b: self.iter_b.make_copy()?,
+            function: self.function.clone(),
+            vm: self.vm.spawn_shared_vm(),
+        };
+        Ok(KIterator::new(result))
+    }
+}
+
+impl Iterator for ZipWith {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter_a.next().map(collect_pair) {
+            Some(Output::Value(value_a)) => match self.iter_b.next().map(collect_pair) {
+                Some(Output::Value(value_b)) => {
+                    match self.vm.run_function(
+                        self.function.clone(),
+                        CallArgs::Separate(&[value_a, value_b]),
+                    ) {
+                        Ok(result) => Some(Output::Value(result)),
+                        Err(error) => Some(Output::Error(error)),
+                    }
+                }
+                error @ Some(Output::Error(_)) => error,
+                _ => None,
+            },
+            error @ Some(Output::Error(_)) => error,
+            _ => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower_a, upper_a) = self.iter_a.size_hint();
+        let (lower_b, upper_b) = self.iter_b.size_hint();
+
+        let lower = lower_a.min(lower_b);
+        let upper = match (upper_a, upper_b) {
+            (Some(upper_a), Some(upper_b)) => Some(upper_a.min(upper_b)),
+            _ => None,
+        };
+
+        (lower, upper)
+    }
+}
+
 // For tests, see runtime/tests/iterator_tests.rs
@@ -17,10 +17,12 @@ pub fn make_module() -> KMap {
         [KValue::Iterator(iter)] => Ok(iter.make_copy()?.into()),
         [KValue::List(l)] => Ok(KList::with_data(l.data().clone()).into()),
         [KValue::Map(m)] => {
-            let result = KMap::with_contents(
+            let mut result = KMap::with_contents(
                 m.data().clone(),
                 m.meta_map().map(|meta| meta.borrow().clone()),
             );
+            result.set_frozen(m.is_frozen());
+            result.set_map_module_fallback(m.has_map_module_fallback());
             Ok(result.into())
         }
         [KValue::Object(o)] => o.try_borrow().map(|o| o.copy().into()),
@@ -11,6 +11,37 @@ use std::{cmp::Ordering, ops::DerefMut};
 pub fn make_module() -> KMap {
     let result = KMap::with_type("core.list");
 
+    result.add_fn("chunk_map", |ctx| {
+        let expected_error = "a List, a chunk size, and a function";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), [KValue::Number(n), f]) if f.is_callable() => {
+                if *n < 1 {
+                    return runtime_error!(
+                        "list.chunk_map: The chunk size must be greater than zero"
+                    );
+                }
+
+                let f = f.clone();
+                // The data is copied so that the list can be modified by the function
+                let data = l.data().clone();
+                let mut result = ValueVec::with_capacity(data.len().div_ceil(n.into()));
+
+                for chunk in data.chunks(n.into()) {
+                    result.push(
+                        ctx.vm.run_function(
+                            f.clone(),
+                            CallArgs::Single(KValue::Tuple(chunk.into())),
+                        )?,
+                    );
+                }
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("clear", |ctx| {
         let expected_error = "a List";
 
@@ -23,6 +54,20 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("concat", |ctx| {
+        let expected_error = "two Lists";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(a), [KValue::List(b)]) => {
+                let mut result = ValueVec::with_capacity(a.len() + b.len());
+                result.extend(a.data().iter().cloned());
+                result.extend(b.data().iter().cloned());
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("contains", |ctx| {
         let expected_error = "a List and a Value";
 
@@ -103,6 +148,30 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("find_index", |ctx| {
+        let expected_error = "a List and a predicate function";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), [f]) if f.is_callable() => {
+                let (l, f) = (l.clone(), f.clone());
+                find_index(ctx.vm, &l, f, 0..l.len())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("find_last_index", |ctx| {
+        let expected_error = "a List and a predicate function";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), [f]) if f.is_callable() => {
+                let (l, f) = (l.clone(), f.clone());
+                find_index(ctx.vm, &l, f, (0..l.len()).rev())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("first", |ctx| {
         let expected_error = "a List";
 
@@ -115,6 +184,24 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("flatten_to_string", |ctx| {
+        let expected_error = "a List and a separator String";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), [KValue::Str(separator)]) => {
+                let l = l.clone();
+                let separator = separator.clone();
+                let mut display_context = DisplayContext::with_vm(ctx.vm);
+                let mut first = true;
+                for value in l.data().iter() {
+                    display_leaves(value, &separator, &mut first, &mut display_context)?;
+                }
+                Ok(display_context.result().into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("get", |ctx| {
         let (list, index, default) = {
             let expected_error = "a List and a Number (with optional default value)";
@@ -149,6 +236,31 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("intersect", |ctx| {
+        let expected_error = "two Lists";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(a), [KValue::List(b)]) => {
+                let mut b_keys = ValueMap::with_capacity(b.len());
+                for value in b.data().iter() {
+                    b_keys.insert(hashable_key("intersect", value)?, KValue::Null);
+                }
+
+                let mut seen = ValueMap::default();
+                let mut result = ValueVec::new();
+                for value in a.data().iter() {
+                    let key = hashable_key("intersect", value)?;
+                    if b_keys.contains_key(&key) && seen.insert(key, KValue::Null).is_none() {
+                        result.push(value.clone());
+                    }
+                }
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("is_empty", |ctx| {
         let expected_error = "a List";
 
@@ -344,6 +456,25 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("shuffle", |ctx| {
+        let expected_error = "a List and a seed Number";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), [KValue::Number(seed)]) => {
+                let mut result = l.data().clone();
+                let mut rng = SeededRng::new(seed.to_bits());
+
+                // Fisher-Yates shuffle
+                for i in (1..result.len()).rev() {
+                    result.swap(i, rng.index(i + 1));
+                }
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("size", |ctx| {
         let expected_error = "a List";
 
@@ -366,50 +497,59 @@ pub fn make_module() -> KMap {
             (KValue::List(l), [f]) if f.is_callable() => {
                 let l = l.clone();
                 let f = f.clone();
+                sort_by_cached_key(ctx.vm, &l, f)?;
+                Ok(KValue::List(l))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
 
-                // apply function and construct a vec of (key, value)
-                let mut pairs = l
-                    .data()
-                    .iter()
-                    .map(|value| {
-                        match ctx
-                            .vm
-                            .run_function(f.clone(), CallArgs::Single(value.clone()))
-                        {
-                            Ok(key) => Ok((key, value.clone())),
-                            Err(e) => Err(e),
-                        }
-                    })
-                    .collect::<Result<Vec<_>, _>>()?;
+    result.add_fn("sort_by_key", |ctx| {
+        let expected_error = "a List and a key function";
 
-                let mut error = None;
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), [f]) if f.is_callable() => {
+                let l = l.clone();
+                let f = f.clone();
+                sort_by_cached_key(ctx.vm, &l, f)?;
+                Ok(KValue::List(l))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
 
-                // sort array by key (i.e. from [key, value])
-                pairs.sort_by(|a, b| {
-                    if error.is_some() {
-                        return Ordering::Equal;
-                    }
+    result.add_fn("split_when", |ctx| {
+        let expected_error = "a List and a predicate function";
 
-                    match compare_values(ctx.vm, &a.0, &b.0) {
-                        Ok(ordering) => ordering,
-                        Err(e) => {
-                            error.get_or_insert(e);
-                            Ordering::Equal
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), [f]) if f.is_callable() => {
+                let f = f.clone();
+                // The data is copied so that the list can be modified by the function
+                let data = l.data().clone();
+
+                let mut result = ValueVec::new();
+                let mut start = 0;
+                for (i, pair) in data.windows(2).enumerate() {
+                    match ctx.vm.run_function(f.clone(), CallArgs::Separate(pair))? {
+                        KValue::Bool(true) => {
+                            result.push(KList::from_slice(&data[start..=i]).into());
+                            start = i + 1;
+                        }
+                        KValue::Bool(false) => {}
+                        unexpected => {
+                            return type_error(
+                                "a Bool to be returned from the predicate",
+                                &unexpected,
+                            )
                         }
                     }
-                });
-
-                if let Some(error) = error {
-                    return Err(error);
                 }
 
-                // collect values
-                *l.data_mut() = pairs
-                    .iter()
-                    .map(|(_key, value)| value.clone())
-                    .collect::<_>();
+                if !data.is_empty() {
+                    result.push(KList::from_slice(&data[start..]).into());
+                }
 
-                Ok(KValue::List(l))
+                Ok(KValue::List(KList::with_data(result)))
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
@@ -427,6 +567,28 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("take_random", |ctx| {
+        let expected_error = "a List, a non-negative Number, and a seed Number";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), [KValue::Number(n), KValue::Number(seed)]) if *n >= 0.0 => {
+                let mut result = l.data().clone();
+                let count = usize::from(n).min(result.len());
+                let mut rng = SeededRng::new(seed.to_bits());
+
+                // A partial Fisher-Yates shuffle, stopping once enough elements have been chosen
+                for i in 0..count {
+                    let j = i + rng.index(result.len() - i);
+                    result.swap(i, j);
+                }
+                result.truncate(count);
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("to_tuple", |ctx| {
         let expected_error = "a List";
 
@@ -460,9 +622,290 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("union", |ctx| {
+        let expected_error = "two Lists";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(a), [KValue::List(b)]) => {
+                let mut seen = ValueMap::default();
+                let mut result = ValueVec::new();
+                for value in a.data().iter().chain(b.data().iter()) {
+                    if seen
+                        .insert(hashable_key("union", value)?, KValue::Null)
+                        .is_none()
+                    {
+                        result.push(value.clone());
+                    }
+                }
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("unique_last", |ctx| {
+        let expected_error = "a List";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), []) => {
+                // The list is traversed in reverse so that the last occurrences are kept,
+                // and then the result is reversed to restore the order of last appearances.
+                let mut seen = ValueMap::default();
+                let mut result = ValueVec::new();
+                for value in l.data().iter().rev() {
+                    if seen
+                        .insert(hashable_key("unique_last", value)?, KValue::Null)
+                        .is_none()
+                    {
+                        result.push(value.clone());
+                    }
+                }
+                result.reverse();
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("windows_reduce", |ctx| {
+        let expected_error = "a List, a window size, an optional initial value, and a function";
+
+        let (l, n, mut carry, f) = match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(l), [KValue::Number(n), f]) if f.is_callable() => {
+                (l.clone(), *n, None, f.clone())
+            }
+            (KValue::List(l), [KValue::Number(n), carry, f]) if f.is_callable() => {
+                (l.clone(), *n, Some(carry.clone()), f.clone())
+            }
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        if n < 1 {
+            return runtime_error!(
+                "list.windows_reduce: The window size must be greater than zero"
+            );
+        }
+
+        // The data is copied so that the list can be modified by the function
+        let data = l.data().clone();
+        let mut result = ValueVec::with_capacity(data.len().saturating_sub(n.into()) + 1);
+
+        for window in data.windows(n.into()) {
+            let window = KValue::Tuple(window.into());
+
+            let value = match &mut carry {
+                // With an initial value, the function returns the value to add to the result,
+                // along with the carried value for the next window.
+                Some(carry) => match ctx
+                    .vm
+                    .run_function(f.clone(), CallArgs::Separate(&[carry.clone(), window]))?
+                {
+                    KValue::Tuple(t) if t.len() == 2 => {
+                        *carry = t[1].clone();
+                        t[0].clone()
+                    }
+                    unexpected => {
+                        return runtime_error!(
+                            "list.windows_reduce: Expected a Tuple containing two values \
+                             to be returned from the function, found '{}'",
+                            unexpected.type_as_string()
+                        )
+                    }
+                },
+                None => ctx.vm.run_function(f.clone(), CallArgs::Single(window))?,
+            };
+
+            result.push(value);
+        }
+
+        Ok(KValue::List(KList::with_data(result)))
+    });
+
+    result.add_fn("zip", |ctx| {
+        let expected_error = "a List and an iterable";
+
+        match ctx.instance_and_args(is_list, expected_error)? {
+            (KValue::List(a), [KValue::List(b)]) => {
+                let result = a
+                    .data()
+                    .iter()
+                    .zip(b.data().iter())
+                    .map(|(a, b)| KValue::Tuple(vec![a.clone(), b.clone()].into()))
+                    .collect::<ValueVec>();
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (KValue::List(a), [iterable]) if iterable.is_iterable() => {
+                let a = a.clone();
+                let iterable = iterable.clone();
+                let mut result = ValueVec::with_capacity(a.len());
+
+                for (a, output) in a
+                    .data()
+                    .iter()
+                    .zip(ctx.vm.make_iterator(iterable)?.map(collect_pair))
+                {
+                    match output {
+                        KIteratorOutput::Value(b) => {
+                            result.push(KValue::Tuple(vec![a.clone(), b].into()))
+                        }
+                        KIteratorOutput::Error(error) => return Err(error),
+                        _ => unreachable!(),
+                    }
+                }
+
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result
 }
 
 fn is_list(value: &KValue) -> bool {
     matches!(value, KValue::List(_))
 }
+
+// Sorts the list using keys produced by the provided function
+//
+// The function is called once for each value, with the resulting keys cached for comparisons.
+// The sort is stable, so values with equal keys keep their original order.
+fn sort_by_cached_key(vm: &mut KotoVm, l: &KList, f: KValue) -> crate::Result<()> {
+    // apply function and construct a vec of (key, value)
+    let mut pairs = l
+        .data()
+        .iter()
+        .map(
+            |value| match vm.run_function(f.clone(), CallArgs::Single(value.clone())) {
+                Ok(key) => Ok((key, value.clone())),
+                Err(e) => Err(e),
+            },
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut error = None;
+
+    // sort array by key (i.e. from [key, value])
+    pairs.sort_by(|a, b| {
+        if error.is_some() {
+            return Ordering::Equal;
+        }
+
+        match compare_values(vm, &a.0, &b.0) {
+            Ok(ordering) => ordering,
+            Err(e) => {
+                error.get_or_insert(e);
+                Ordering::Equal
+            }
+        }
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    // collect values
+    *l.data_mut() = pairs
+        .iter()
+        .map(|(_key, value)| value.clone())
+        .collect::<_>();
+
+    Ok(())
+}
+
+// Displays the value, recursing into nested lists and tuples
+//
+// The separator is added before each displayed value apart from the first.
+fn display_leaves(
+    value: &KValue,
+    separator: &str,
+    first: &mut bool,
+    ctx: &mut DisplayContext,
+) -> crate::Result<()> {
+    match value {
+        KValue::List(l) => {
+            for value in l.data().iter() {
+                display_leaves(value, separator, first, ctx)?;
+            }
+        }
+        KValue::Tuple(t) => {
+            for value in t.iter() {
+                display_leaves(value, separator, first, ctx)?;
+            }
+        }
+        _ => {
+            if !*first {
+                ctx.append(separator);
+            }
+            *first = false;
+
+            match value {
+                KValue::Str(s) => ctx.append(s),
+                _ => value.display(ctx)?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Makes a ValueKey from the value, or returns an error if the value isn't hashable
+fn hashable_key(fn_name: &str, value: &KValue) -> crate::Result<ValueKey> {
+    if value.is_hashable() {
+        ValueKey::try_from(value.clone())
+    } else {
+        runtime_error!(
+            "list.{fn_name}: Expected hashable values, found '{}'",
+            value.type_as_string()
+        )
+    }
+}
+
+// Returns the first index in `indices` for which the predicate returns true, or null
+fn find_index(
+    vm: &mut KotoVm,
+    list: &KList,
+    predicate: KValue,
+    indices: impl Iterator<Item = usize>,
+) -> crate::Result<KValue> {
+    for i in indices {
+        // The list might have been modified by the predicate
+        let Some(value) = list.data().get(i).cloned() else {
+            continue;
+        };
+
+        match vm.run_function(predicate.clone(), CallArgs::Single(value))? {
+            KValue::Bool(true) => return Ok(i.into()),
+            KValue::Bool(false) => {}
+            unexpected => {
+                return type_error("a Bool to be returned from the predicate", &unexpected)
+            }
+        }
+    }
+
+    Ok(KValue::Null)
+}
+
+// A minimal seeded random number generator (SplitMix64) used for reproducible shuffling
+struct SeededRng(u64);
+
+impl SeededRng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // Returns a random index in the range 0..upper
+    fn index(&mut self, upper: usize) -> usize {
+        (self.next_u64() % upper as u64) as usize
+    }
+}
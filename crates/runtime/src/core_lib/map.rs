@@ -11,7 +11,7 @@ pub fn make_module() -> KMap {
     result.add_fn("clear", |ctx| {
         let expected_error = "a Map";
 
-        match map_instance_and_args(ctx, expected_error)? {
+        match mutable_map_instance_and_args(ctx, "clear", expected_error)? {
             (KValue::Map(m), []) => {
                 m.data_mut().clear();
                 Ok(KValue::Map(m.clone()))
@@ -20,6 +20,28 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("compute_if_absent", |ctx| {
+        let expected_error = "a Map, a key, and a function";
+
+        match mutable_map_instance_and_args(ctx, "compute_if_absent", expected_error)? {
+            (KValue::Map(m), [key, f]) if f.is_callable() => {
+                let m = m.clone();
+                let key_value = key.clone();
+                let key = ValueKey::try_from(key.clone())?;
+                let f = f.clone();
+
+                if let Some(value) = m.data().get(&key) {
+                    return Ok(value.clone());
+                }
+
+                let value = ctx.vm.run_function(f, CallArgs::Single(key_value))?;
+                m.data_mut().insert(key, value.clone());
+                Ok(value)
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("contains_key", |ctx| {
         let expected_error = "a Map and key";
 
@@ -32,10 +54,92 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("diff", |ctx| {
+        let expected_error = "two Maps";
+
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(a), [KValue::Map(b)]) => {
+                let a = a.data().clone();
+                let b = b.data().clone();
+
+                let mut added = ValueMap::default();
+                let mut removed = ValueMap::default();
+                let mut changed = ValueMap::default();
+
+                for (key, old_value) in a.iter() {
+                    match b.get(key) {
+                        Some(new_value) => {
+                            match ctx.vm.run_binary_op(
+                                BinaryOp::Equal,
+                                old_value.clone(),
+                                new_value.clone(),
+                            )? {
+                                KValue::Bool(true) => {}
+                                KValue::Bool(false) => {
+                                    let old_and_new = vec![old_value.clone(), new_value.clone()];
+                                    changed.insert(key.clone(), KValue::Tuple(old_and_new.into()));
+                                }
+                                unexpected => {
+                                    return type_error(
+                                        "a Bool from the equality comparison",
+                                        &unexpected,
+                                    )
+                                }
+                            }
+                        }
+                        None => {
+                            removed.insert(key.clone(), old_value.clone());
+                        }
+                    }
+                }
+
+                for (key, new_value) in b.iter() {
+                    if !a.contains_key(key) {
+                        added.insert(key.clone(), new_value.clone());
+                    }
+                }
+
+                let result = KMap::with_capacity(3);
+                result.insert("added", KMap::with_data(added));
+                result.insert("removed", KMap::with_data(removed));
+                result.insert("changed", KMap::with_data(changed));
+                Ok(result.into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("difference_keys", |ctx| {
+        let expected_error = "two Maps";
+
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(a), [KValue::Map(b)]) => Ok(filter_by_keys(a, b, false).into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("entries_list", |ctx| {
+        let expected_error = "a Map";
+
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), []) => {
+                let result = m
+                    .data()
+                    .iter()
+                    .map(|(key, value)| {
+                        KValue::Tuple(vec![key.value().clone(), value.clone()].into())
+                    })
+                    .collect::<ValueVec>();
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("extend", |ctx| {
         let expected_error = "a Map and an iterable";
 
-        match map_instance_and_args(ctx, expected_error)? {
+        match mutable_map_instance_and_args(ctx, "extend", expected_error)? {
             (KValue::Map(m), [KValue::Map(other)]) => {
                 m.data_mut().extend(
                     other
@@ -78,6 +182,44 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("for_each_value", |ctx| {
+        let expected_error = "a Map and function";
+
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), [f]) if f.is_callable() => {
+                let m = m.clone();
+                let f = f.clone();
+
+                // The map isn't borrowed while the function is being called,
+                // so values are looked up by index.
+                for index in 0.. {
+                    let Some(value) = m.data().get_index(index).map(|(_, value)| value.clone())
+                    else {
+                        break;
+                    };
+
+                    ctx.vm.run_function(f.clone(), CallArgs::Single(value))?;
+                }
+
+                Ok(KValue::Map(m))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("freeze", |ctx| {
+        let expected_error = "a Map";
+
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), []) => {
+                let mut result = m.clone();
+                result.set_frozen(true);
+                Ok(result.into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("get", |ctx| {
         let (map, key, default) = {
             let expected_error = "a Map and a key, with an optional default value";
@@ -135,7 +277,7 @@ pub fn make_module() -> KMap {
     result.add_fn("insert", |ctx| {
         let expected_error = "a Map and key (with optional Value to insert)";
 
-        match map_instance_and_args(ctx, expected_error)? {
+        match mutable_map_instance_and_args(ctx, "insert", expected_error)? {
             (KValue::Map(m), [key]) => match m
                 .data_mut()
                 .insert(ValueKey::try_from(key.clone())?, KValue::Null)
@@ -156,6 +298,15 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("intersect_keys", |ctx| {
+        let expected_error = "two Maps";
+
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(a), [KValue::Map(b)]) => Ok(filter_by_keys(a, b, true).into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("is_empty", |ctx| {
         let expected_error = "a Map";
 
@@ -165,6 +316,22 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("iter_sorted", |ctx| {
+        let expected_error = "a Map and optional sort key function";
+
+        let (m, key_fn) = match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), []) => (m.clone(), None),
+            (KValue::Map(m), [f]) if f.is_callable() => (m.clone(), Some(f.clone())),
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        // Sort a copy of the map's entries, leaving the source map unmodified
+        let mut sorted = m.data().clone();
+        sort_map_data(&mut sorted, key_fn, ctx.vm)?;
+
+        Ok(KIterator::with_map(KMap::with_data(sorted)).into())
+    });
+
     result.add_fn("keys", |ctx| {
         let expected_error = "a Map";
 
@@ -177,124 +344,147 @@ pub fn make_module() -> KMap {
         }
     });
 
-    result.add_fn("remove", |ctx| {
-        let expected_error = "a Map and key";
+    result.add_fn("keys_list", |ctx| {
+        let expected_error = "a Map";
 
         match map_instance_and_args(ctx, expected_error)? {
-            (KValue::Map(m), [key]) => {
-                match m.data_mut().shift_remove(&ValueKey::try_from(key.clone())?) {
-                    Some(old_value) => Ok(old_value),
-                    None => Ok(KValue::Null),
-                }
+            (KValue::Map(m), []) => {
+                let data = m.data();
+                let mut result = ValueVec::with_capacity(data.len());
+                result.extend(data.keys().map(|key| key.value().clone()));
+                Ok(KValue::List(KList::with_data(result)))
             }
             (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
     });
 
-    result.add_fn("size", |ctx| {
-        let expected_error = "a Map";
+    result.add_fn("keys_sorted", |ctx| {
+        let expected_error = "a Map and optional sort key function";
 
-        match map_instance_and_args(ctx, expected_error)? {
-            (KValue::Map(m), []) => Ok(KValue::Number(m.len().into())),
-            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
-        }
+        let (m, key_fn) = match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), []) => (m.clone(), None),
+            (KValue::Map(m), [f]) if f.is_callable() => (m.clone(), Some(f.clone())),
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        let keys = m.data().keys().map(|key| key.value().clone()).collect();
+        let result = sort_values_with_key(keys, key_fn, ctx.vm)?;
+        Ok(KValue::List(KList::with_data(result)))
     });
 
-    result.add_fn("sort", |ctx| {
-        let expected_error = "a Map and optional sort key function";
+    result.add_fn("partition", |ctx| {
+        let expected_error = "a Map and a predicate function";
 
         match map_instance_and_args(ctx, expected_error)? {
-            (KValue::Map(m), []) => {
-                let mut error = None;
-                m.data_mut().sort_by(|key_a, _, key_b, _| {
-                    if error.is_some() {
-                        return Ordering::Equal;
-                    }
+            (KValue::Map(m), [f]) if f.is_callable() => {
+                let data = m.data().clone();
+                let f = f.clone();
+                let mut matched = ValueMap::default();
+                let mut unmatched = ValueMap::default();
 
-                    match key_a.partial_cmp(key_b) {
-                        Some(ordering) => ordering,
-                        None => {
-                            // This should never happen, ValueKeys can only be made with sortable values
-                            error = Some(runtime_error!("Invalid map key encountered"));
-                            Ordering::Equal
+                for (key, value) in data.iter() {
+                    match ctx.vm.run_function(
+                        f.clone(),
+                        CallArgs::Separate(&[key.value().clone(), value.clone()]),
+                    )? {
+                        KValue::Bool(true) => matched.insert(key.clone(), value.clone()),
+                        KValue::Bool(false) => unmatched.insert(key.clone(), value.clone()),
+                        unexpected => {
+                            return type_error(
+                                "a Bool to be returned from the predicate",
+                                &unexpected,
+                            )
                         }
-                    }
-                });
+                    };
+                }
 
-                if let Some(error) = error {
-                    error
-                } else {
-                    Ok(KValue::Map(m.clone()))
+                let result = vec![
+                    KMap::with_data(matched).into(),
+                    KMap::with_data(unmatched).into(),
+                ];
+                Ok(KValue::Tuple(result.into()))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("remove", |ctx| {
+        let expected_error = "a Map and key";
+
+        match mutable_map_instance_and_args(ctx, "remove", expected_error)? {
+            (KValue::Map(m), [key]) => {
+                match m.data_mut().shift_remove(&ValueKey::try_from(key.clone())?) {
+                    Some(old_value) => Ok(old_value),
+                    None => Ok(KValue::Null),
                 }
             }
-            (KValue::Map(m), [f]) if f.is_callable() => {
-                let m = m.clone();
-                let f = f.clone();
-                let mut error = None;
-
-                let get_sort_key = |vm: &mut KotoVm,
-                                    cache: &mut ValueMap,
-                                    key: &ValueKey,
-                                    value: &KValue|
-                 -> Result<KValue> {
-                    let value = vm.run_function(
-                        f.clone(),
-                        CallArgs::Separate(&[key.value().clone(), value.clone()]),
-                    )?;
-                    cache.insert(key.clone(), value.clone());
-                    Ok(value)
-                };
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
 
-                let mut cache = ValueMap::with_capacity(m.len());
-                m.data_mut().sort_by(|key_a, value_a, key_b, value_b| {
-                    if error.is_some() {
-                        return Ordering::Equal;
-                    }
+    result.add_fn("rename_keys", |ctx| {
+        let expected_error = "two Maps";
 
-                    let value_a = match cache.get(key_a) {
-                        Some(value) => value.clone(),
-                        None => match get_sort_key(ctx.vm, &mut cache, key_a, value_a) {
-                            Ok(val) => val,
-                            Err(e) => {
-                                error.get_or_insert(Err(e));
-                                KValue::Null
-                            }
-                        },
-                    };
-                    let value_b = match cache.get(key_b) {
-                        Some(value) => value.clone(),
-                        None => match get_sort_key(ctx.vm, &mut cache, key_b, value_b) {
-                            Ok(val) => val,
-                            Err(e) => {
-                                error.get_or_insert(Err(e));
-                                KValue::Null
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), [KValue::Map(renames)]) => {
+                let renames = renames.data();
+                let mut result = ValueMap::with_capacity(m.len());
+
+                for (key, value) in m.data().iter() {
+                    let new_key = match renames.get(key) {
+                        Some(new_key) => match ValueKey::try_from(new_key.clone()) {
+                            Ok(new_key) => new_key,
+                            Err(_) => {
+                                return runtime_error!(
+                                    "map.rename_keys: Expected a hashable key, found '{}'",
+                                    new_key.type_as_string()
+                                )
                             }
                         },
+                        None => key.clone(),
                     };
 
-                    match compare_values(ctx.vm, &value_a, &value_b) {
-                        Ok(ordering) => ordering,
-                        Err(e) => {
-                            error.get_or_insert(Err(e));
-                            Ordering::Equal
-                        }
+                    if result.contains_key(&new_key) {
+                        return runtime_error!(
+                            "map.rename_keys: The key '{new_key}' is used more than once"
+                        );
                     }
-                });
 
-                if let Some(error) = error {
-                    error
-                } else {
-                    Ok(KValue::Map(m))
+                    result.insert(new_key, value.clone());
                 }
+
+                Ok(KMap::with_data(result).into())
             }
-            (_, unexpected) => type_error_with_slice("a Map ", unexpected),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
         }
     });
 
+    result.add_fn("size", |ctx| {
+        let expected_error = "a Map";
+
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), []) => Ok(KValue::Number(m.len().into())),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("sort", |ctx| {
+        let expected_error = "a Map and optional sort key function";
+
+        let (m, key_fn) = match mutable_map_instance_and_args(ctx, "sort", expected_error)? {
+            (KValue::Map(m), []) => (m.clone(), None),
+            (KValue::Map(m), [f]) if f.is_callable() => (m.clone(), Some(f.clone())),
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        sort_map_data(&mut m.data_mut(), key_fn, ctx.vm)?;
+        Ok(KValue::Map(m))
+    });
+
     result.add_fn("update", |ctx| {
         let expected_error = "a Map, key, optional default Value, and update function";
 
-        match map_instance_and_args(ctx, expected_error)? {
+        match mutable_map_instance_and_args(ctx, "update", expected_error)? {
             (KValue::Map(m), [key, f]) if f.is_callable() => do_map_update(
                 m.clone(),
                 ValueKey::try_from(key.clone())?,
@@ -313,6 +503,40 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("update_all", |ctx| {
+        let expected_error = "a Map and update function";
+
+        match mutable_map_instance_and_args(ctx, "update_all", expected_error)? {
+            (KValue::Map(m), [f]) if f.is_callable() => {
+                let m = m.clone();
+                let f = f.clone();
+
+                // The map isn't borrowed while the update function is being called,
+                // so entries are looked up by index for each update.
+                for index in 0.. {
+                    let Some((key, value)) = m
+                        .data()
+                        .get_index(index)
+                        .map(|(key, value)| (key.value().clone(), value.clone()))
+                    else {
+                        break;
+                    };
+
+                    let new_value = ctx
+                        .vm
+                        .run_function(f.clone(), CallArgs::Separate(&[key, value]))?;
+
+                    if let Some((_, value)) = m.data_mut().get_index_mut(index) {
+                        *value = new_value;
+                    }
+                }
+
+                Ok(KValue::Map(m))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("values", |ctx| {
         let expected_error = "a Map";
 
@@ -325,11 +549,40 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("values_list", |ctx| {
+        let expected_error = "a Map";
+
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), []) => {
+                let data = m.data();
+                let mut result = ValueVec::with_capacity(data.len());
+                result.extend(data.values().cloned());
+                Ok(KValue::List(KList::with_data(result)))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("values_sorted", |ctx| {
+        let expected_error = "a Map and optional sort key function";
+
+        let (m, key_fn) = match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), []) => (m.clone(), None),
+            (KValue::Map(m), [f]) if f.is_callable() => (m.clone(), Some(f.clone())),
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        let values = m.data().values().cloned().collect();
+        let result = sort_values_with_key(values, key_fn, ctx.vm)?;
+        Ok(KValue::List(KList::with_data(result)))
+    });
+
     result.add_fn("set_meta", |ctx| {
         let expected_error = "two Maps";
 
         match map_instance_and_args(ctx, expected_error)? {
             (KValue::Map(data), [KValue::Map(meta)]) => {
+                // The cloned map keeps the frozen state of the input map
                 let mut data = data.clone();
                 data.set_meta_map(meta.meta_map().cloned());
                 Ok(data.into())
@@ -338,9 +591,177 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("with_default", |ctx| {
+        let expected_error = "a Map and a fallback value";
+
+        match map_instance_and_args(ctx, expected_error)? {
+            (KValue::Map(m), [fallback]) => {
+                let fallback = fallback.clone();
+                let mut meta = m
+                    .meta_map()
+                    .map(|meta| meta.borrow().clone())
+                    .unwrap_or_default();
+                meta.insert(
+                    MetaKey::Named("get".into()),
+                    map_default_get(fallback.clone()).into(),
+                );
+                meta.insert(
+                    MetaKey::BinaryOp(BinaryOp::Index),
+                    map_default_index(fallback).into(),
+                );
+
+                // The cloned map keeps the frozen state of the input map
+                let mut result = m.clone();
+                result.set_meta_map(Some(meta.into()));
+                // Maps with meta maps don't usually fall back to the map module for lookups,
+                // a regular map should keep behaving like a map with the fallback added.
+                result.set_map_module_fallback(m.has_map_module_fallback());
+                Ok(result.into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result
 }
 
+// Sorts the values using `compare_values`, with an optional function that provides sort keys
+//
+// The key function is called once for each value.
+fn sort_values_with_key(
+    values: ValueVec,
+    sort_key_fn: Option<KValue>,
+    vm: &mut KotoVm,
+) -> Result<ValueVec> {
+    let mut keys_and_values = values
+        .into_iter()
+        .map(|value| {
+            let key = match &sort_key_fn {
+                Some(f) => vm.run_function(f.clone(), CallArgs::Single(value.clone()))?,
+                None => value.clone(),
+            };
+            Ok((key, value))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut error = None;
+    keys_and_values.sort_by(|(key_a, _), (key_b, _)| {
+        if error.is_some() {
+            return Ordering::Equal;
+        }
+
+        match compare_values(vm, key_a, key_b) {
+            Ok(ordering) => ordering,
+            Err(e) => {
+                error.get_or_insert(e);
+                Ordering::Equal
+            }
+        }
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(keys_and_values
+        .into_iter()
+        .map(|(_key, value)| value)
+        .collect())
+}
+
+// Sorts the map's entries by key, or by the result of an optional sort key function
+//
+// The sort key function gets called once per entry, with the results cached for comparisons.
+fn sort_map_data(data: &mut ValueMap, sort_key_fn: Option<KValue>, vm: &mut KotoVm) -> Result<()> {
+    let mut error = None;
+
+    match sort_key_fn {
+        None => {
+            data.sort_by(|key_a, _, key_b, _| {
+                if error.is_some() {
+                    return Ordering::Equal;
+                }
+
+                match key_a.partial_cmp(key_b) {
+                    Some(ordering) => ordering,
+                    None => {
+                        // This should never happen, ValueKeys can only be made with sortable values
+                        error = Some(runtime_error!("Invalid map key encountered"));
+                        Ordering::Equal
+                    }
+                }
+            });
+        }
+        Some(f) => {
+            let get_sort_key = |vm: &mut KotoVm,
+                                cache: &mut ValueMap,
+                                key: &ValueKey,
+                                value: &KValue|
+             -> Result<KValue> {
+                let value = vm.run_function(
+                    f.clone(),
+                    CallArgs::Separate(&[key.value().clone(), value.clone()]),
+                )?;
+                cache.insert(key.clone(), value.clone());
+                Ok(value)
+            };
+
+            let mut cache = ValueMap::with_capacity(data.len());
+            data.sort_by(|key_a, value_a, key_b, value_b| {
+                if error.is_some() {
+                    return Ordering::Equal;
+                }
+
+                let value_a = match cache.get(key_a) {
+                    Some(value) => value.clone(),
+                    None => match get_sort_key(vm, &mut cache, key_a, value_a) {
+                        Ok(val) => val,
+                        Err(e) => {
+                            error.get_or_insert(Err(e));
+                            KValue::Null
+                        }
+                    },
+                };
+                let value_b = match cache.get(key_b) {
+                    Some(value) => value.clone(),
+                    None => match get_sort_key(vm, &mut cache, key_b, value_b) {
+                        Ok(val) => val,
+                        Err(e) => {
+                            error.get_or_insert(Err(e));
+                            KValue::Null
+                        }
+                    },
+                };
+
+                match compare_values(vm, &value_a, &value_b) {
+                    Ok(ordering) => ordering,
+                    Err(e) => {
+                        error.get_or_insert(Err(e));
+                        Ordering::Equal
+                    }
+                }
+            });
+        }
+    }
+
+    match error {
+        Some(error) => error,
+        None => Ok(()),
+    }
+}
+
+// Returns a map containing the entries from `a`, either keeping or excluding keys found in `b`
+fn filter_by_keys(a: &KMap, b: &KMap, keep_shared_keys: bool) -> KMap {
+    let b = b.data();
+    let result = a
+        .data()
+        .iter()
+        .filter(|(key, _)| b.contains_key(*key) == keep_shared_keys)
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<ValueMap>();
+    KMap::with_data(result)
+}
+
 fn do_map_update(
     map: KMap,
     key: ValueKey,
@@ -370,8 +791,55 @@ fn map_instance_and_args<'a>(
     // For core.map ops, allow using maps with metamaps when the ops are used as standalone
     // functions.
     match (ctx.instance(), ctx.args()) {
-        (Some(instance @ Map(m)), args) if m.meta_map().is_none() => Ok((instance, args)),
+        (Some(instance @ Map(m)), args) if m.has_map_module_fallback() => Ok((instance, args)),
         (_, [first @ Map(_), rest @ ..]) => Ok((first, rest)),
         (_, unexpected_args) => type_error_with_slice(expected_error, unexpected_args),
     }
 }
+
+// Used by the map module's mutating functions, throws an error if the map has been frozen
+fn mutable_map_instance_and_args<'a>(
+    ctx: &'a CallContext<'_>,
+    fn_name: &str,
+    expected_error: &str,
+) -> Result<(&'a KValue, &'a [KValue])> {
+    match map_instance_and_args(ctx, expected_error)? {
+        (KValue::Map(m), _) if m.is_frozen() => {
+            runtime_error!("map.{fn_name}: Unable to modify a frozen map")
+        }
+        result => Ok(result),
+    }
+}
+
+// Returns a `get` function for maps created with `map.with_default`
+//
+// The fallback is returned for missing keys, unless a default value is provided.
+fn map_default_get(fallback: KValue) -> KNativeFunction {
+    KNativeFunction::new(move |ctx| {
+        let expected_error = "a Map and a key, with an optional default value";
+
+        let (map, key, default) = match (ctx.instance(), ctx.args()) {
+            (Some(KValue::Map(map)), [key]) => (map, key, &fallback),
+            (Some(KValue::Map(map)), [key, default]) => (map, key, default),
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        match map.data().get(&ValueKey::try_from(key.clone())?) {
+            Some(value) => Ok(value.clone()),
+            None => Ok(default.clone()),
+        }
+    })
+}
+
+// Returns an `@[]` function for maps created with `map.with_default`
+fn map_default_index(fallback: KValue) -> KNativeFunction {
+    KNativeFunction::new(move |ctx| match (ctx.instance(), ctx.args()) {
+        (Some(KValue::Map(map)), [key]) => {
+            match map.data().get(&ValueKey::try_from(key.clone())?) {
+                Some(value) => Ok(value.clone()),
+                None => Ok(fallback.clone()),
+            }
+        }
+        (_, unexpected) => type_error_with_slice("a Map and a key", unexpected),
+    })
+}
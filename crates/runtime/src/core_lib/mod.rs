@@ -6,6 +6,7 @@ pub mod koto;
 pub mod list;
 pub mod map;
 pub mod number;
+pub mod object;
 pub mod os;
 pub mod range;
 pub mod string;
@@ -25,6 +26,7 @@ pub struct CoreLib {
     pub map: KMap,
     pub os: KMap,
     pub number: KMap,
+    pub object: KMap,
     pub range: KMap,
     pub string: KMap,
     pub test: KMap,
@@ -76,6 +78,7 @@ impl Default for CoreLib {
             map: map::make_module(),
             os: os::make_module(),
             number: number::make_module(),
+            object: object::make_module(),
             range: range::make_module(),
             string: string::make_module(),
             test: test::make_module(),
@@ -1,6 +1,7 @@
 //! The `number` core library module
 
-use crate::prelude::*;
+use super::iterator::collect_pair;
+use crate::{prelude::*, Result};
 
 /// Initializes the `number` core library module
 pub fn make_module() -> KMap {
@@ -69,10 +70,55 @@ pub fn make_module() -> KMap {
         };
     }
 
+    macro_rules! bit_count_fn {
+        ($name:expr, $fn:ident) => {
+            result.add_fn($name, |ctx| {
+                use KNumber::I64;
+                let expected_error = "an Integer";
+
+                match ctx.instance_and_args(is_integer, expected_error)? {
+                    (Number(I64(n)), []) => Ok(n.$fn().into()),
+                    (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+                }
+            })
+        };
+    }
+
+    macro_rules! saturating_fn {
+        ($name:ident) => {
+            result.add_fn(stringify!($name), |ctx| {
+                use KNumber::I64;
+                let expected_error = "two Integers";
+
+                match ctx.instance_and_args(is_integer, expected_error)? {
+                    (Number(I64(a)), [Number(I64(b))]) => Ok(a.$name(*b).into()),
+                    (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+                }
+            })
+        };
+    }
+
     number_fn!(abs);
     number_f64_fn!(acos);
     number_f64_fn!(acosh);
     bitwise_fn!(and, &);
+
+    result.add_fn("approx_equal", |ctx| {
+        let expected_error = "two Numbers, and an optional non-negative tolerance";
+
+        let (a, b, epsilon) = match ctx.instance_and_args(is_number, expected_error)? {
+            (Number(a), [Number(b)]) => (f64::from(a), f64::from(b), 1.0e-9),
+            (Number(a), [Number(b), Number(epsilon)]) if *epsilon >= 0 => {
+                (f64::from(a), f64::from(b), f64::from(epsilon))
+            }
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        // Exact equality is checked first so that matching infinities are considered equal,
+        // NaN is never equal to anything.
+        Ok((a == b || (a - b).abs() <= epsilon).into())
+    });
+
     number_f64_fn!(asin);
     number_f64_fn!(asinh);
     number_f64_fn!(atan);
@@ -87,6 +133,9 @@ pub fn make_module() -> KMap {
         }
     });
 
+    bit_count_fn!("bit_count", count_ones);
+
+    number_f64_fn!(cbrt);
     number_fn!(ceil);
 
     result.add_fn("clamp", |ctx| {
@@ -98,6 +147,15 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("clamp01", |ctx| {
+        let expected_error = "a Number";
+
+        match ctx.instance_and_args(is_number, expected_error)? {
+            (Number(x), []) => Ok(f64::from(x).clamp(0.0, 1.0).into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     number_f64_fn!(cos);
     number_f64_fn!(cosh);
     number_f64_fn!("degrees", to_degrees);
@@ -118,6 +176,27 @@ pub fn make_module() -> KMap {
 
     number_fn!(floor);
 
+    result.add_fn("format_si", |ctx| {
+        let expected_error = "a Number, and an optional non-negative precision";
+
+        match ctx.instance_and_args(is_number, expected_error)? {
+            (Number(x), []) => Ok(format_si(f64::from(x), 2).into()),
+            (Number(x), [Number(KNumber::I64(precision))]) if *precision >= 0 => {
+                Ok(format_si(f64::from(x), *precision as usize).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("hypot", |ctx| {
+        let expected_error = "two Numbers";
+
+        match ctx.instance_and_args(is_number, expected_error)? {
+            (Number(a), [Number(b)]) => Ok(f64::from(a).hypot(f64::from(b)).into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.insert("infinity", Number(std::f64::INFINITY.into()));
 
     result.add_fn("is_nan", |ctx| {
@@ -129,6 +208,8 @@ pub fn make_module() -> KMap {
         }
     });
 
+    bit_count_fn!("leading_zeros", leading_zeros);
+
     result.add_fn("lerp", |ctx| {
         let expected_error = "three Numbers";
 
@@ -142,6 +223,33 @@ pub fn make_module() -> KMap {
     });
 
     number_f64_fn!(ln);
+    result.add_fn("log", |ctx| {
+        let expected_error = "two Numbers";
+
+        match ctx.instance_and_args(is_number, expected_error)? {
+            (Number(n), [Number(base)]) => {
+                if *n <= 0 {
+                    return runtime_error!("number.log: The value must be greater than zero");
+                }
+                if *base <= 0 || *base == 1 {
+                    return runtime_error!(
+                        "number.log: The base must be greater than zero and not equal to 1"
+                    );
+                }
+
+                let n = f64::from(n);
+                // log2 and log10 are more accurate than the general-purpose log
+                let result = match f64::from(base) {
+                    2.0 => n.log2(),
+                    10.0 => n.log10(),
+                    base => n.log(base),
+                };
+                Ok(result.into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     number_f64_fn!(log2);
     number_f64_fn!(log10);
 
@@ -154,6 +262,35 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("mean", |ctx| match ctx.args() {
+        [iterable] if iterable.is_iterable() => {
+            let numbers = collect_numbers(ctx.vm, iterable.clone())?;
+            if numbers.is_empty() {
+                return Ok(KValue::Null);
+            }
+
+            Ok(mean(&numbers).into())
+        }
+        unexpected => type_error_with_slice("an iterable", unexpected),
+    });
+
+    result.add_fn("median", |ctx| match ctx.args() {
+        [iterable] if iterable.is_iterable() => {
+            let mut numbers = collect_numbers(ctx.vm, iterable.clone())?;
+            numbers.sort();
+
+            let mid = numbers.len() / 2;
+            let result = match numbers.len() {
+                0 => KValue::Null,
+                n if n % 2 == 1 => Number(numbers[mid]),
+                _ => ((f64::from(numbers[mid - 1]) + f64::from(numbers[mid])) / 2.0).into(),
+            };
+
+            Ok(result)
+        }
+        unexpected => type_error_with_slice("an iterable", unexpected),
+    });
+
     result.add_fn("min", |ctx| {
         let expected_error = "two Numbers";
 
@@ -185,17 +322,60 @@ pub fn make_module() -> KMap {
     number_f64_fn!(recip);
     number_fn!(round);
 
+    saturating_fn!(saturating_add);
+    saturating_fn!(saturating_mul);
+    saturating_fn!(saturating_sub);
+
     bitwise_fn_positive_arg!(shift_left, <<);
     bitwise_fn_positive_arg!(shift_right, >>);
 
     number_f64_fn!(sin);
     number_f64_fn!(sinh);
     number_f64_fn!(sqrt);
+    result.add_fn("stddev", |ctx| match ctx.args() {
+        [iterable] if iterable.is_iterable() => {
+            let numbers = collect_numbers(ctx.vm, iterable.clone())?;
+            if numbers.is_empty() {
+                return Ok(KValue::Null);
+            }
+
+            // The population standard deviation
+            let mean = mean(&numbers);
+            let variance = numbers
+                .iter()
+                .map(|n| (f64::from(n) - mean).powi(2))
+                .sum::<f64>()
+                / numbers.len() as f64;
+
+            Ok(variance.sqrt().into())
+        }
+        unexpected => type_error_with_slice("an iterable", unexpected),
+    });
+
     number_f64_fn!(tan);
     number_f64_fn!(tanh);
 
     result.insert("tau", std::f64::consts::TAU);
 
+    result.add_fn("test_bit", |ctx| {
+        use KNumber::I64;
+        let expected_error = "two Integers";
+
+        match ctx.instance_and_args(is_integer, expected_error)? {
+            (Number(I64(n)), [Number(I64(i))]) => {
+                if (0..i64::BITS as i64).contains(i) {
+                    Ok((n & (1 << i) != 0).into())
+                } else {
+                    runtime_error!(
+                        "number.test_bit: The bit index {i} is out of range (0..{})",
+                        i64::BITS
+                    )
+                }
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("to_float", |ctx| {
         let expected_error = "a Number";
 
@@ -214,6 +394,64 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("to_ordinal", |ctx| {
+        let expected_error = "an Integer";
+
+        match ctx.instance_and_args(is_integer, expected_error)? {
+            (Number(KNumber::I64(n)), []) => {
+                let suffix = match (n.unsigned_abs() % 10, n.unsigned_abs() % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                Ok(format!("{n}{suffix}").into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("to_words", |ctx| {
+        let expected_error = "an Integer";
+
+        match ctx.instance_and_args(is_integer, expected_error)? {
+            (Number(KNumber::I64(n)), []) => Ok(integer_to_words(*n).into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    bit_count_fn!("trailing_zeros", trailing_zeros);
+
+    result.add_fn("wrap", |ctx| {
+        use KNumber::{F64, I64};
+        let expected_error = "three Numbers (with the minimum less than the maximum)";
+
+        match ctx.instance_and_args(is_number, expected_error)? {
+            (Number(I64(x)), [Number(I64(min)), Number(I64(max))]) if min < max => {
+                // i128 is used for the intermediate values to avoid overflow
+                let (x, min, max) = (*x as i128, *min as i128, *max as i128);
+                let result = min + (x - min).rem_euclid(max - min);
+                Ok((result as i64).into())
+            }
+            (Number(x), [Number(min), Number(max)]) if min < max => {
+                let (x, min, max) = (f64::from(x), f64::from(min), f64::from(max));
+                Ok(Number(F64(min + (x - min).rem_euclid(max - min))))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("wrap_index", |ctx| {
+        use KNumber::I64;
+        let expected_error = "two Integers (with a positive second Integer)";
+
+        match ctx.instance_and_args(is_integer, expected_error)? {
+            (Number(I64(i)), [Number(I64(len))]) if *len > 0 => Ok(i.rem_euclid(*len).into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     bitwise_fn!(xor, ^);
 
     result
@@ -226,3 +464,166 @@ fn is_number(value: &KValue) -> bool {
 fn is_integer(value: &KValue) -> bool {
     matches!(value, KValue::Number(KNumber::I64(_)))
 }
+
+// Collects the values from an iterable into a Vec, throwing an error for non-numeric values
+fn collect_numbers(vm: &mut KotoVm, iterable: KValue) -> Result<Vec<KNumber>> {
+    use KIteratorOutput as Output;
+
+    let iterator = vm.make_iterator(iterable)?;
+    let mut result = Vec::with_capacity(iterator.size_hint().0);
+
+    for output in iterator.map(collect_pair) {
+        match output {
+            Output::Value(KValue::Number(n)) => result.push(n),
+            Output::Value(unexpected) => return type_error("a Number", &unexpected),
+            Output::Error(error) => return Err(error),
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(result)
+}
+
+fn mean(numbers: &[KNumber]) -> f64 {
+    numbers.iter().map(f64::from).sum::<f64>() / numbers.len() as f64
+}
+
+// Formats a number with an SI prefix, e.g. 1500 -> 1.5k, 0.002 -> 2m
+//
+// The number is rounded to the given number of decimal places, with trailing zeros removed.
+// Numbers outside of the range of prefixes are formatted with an exponent, e.g. 1e30.
+fn format_si(x: f64, precision: usize) -> String {
+    const PREFIXES: [&str; 17] = [
+        "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+    ];
+    // The index of the empty prefix in PREFIXES
+    const UNIT_INDEX: i32 = 8;
+
+    if x == 0.0 || !x.is_finite() {
+        return x.to_string();
+    }
+
+    // The formatter rejects precisions above u16::MAX, trailing zeros get removed anyway
+    let precision = precision.min(u16::MAX as usize);
+
+    let max_index = PREFIXES.len() as i32 - 1;
+    let mut index = (x.abs().log10() / 3.0).floor() as i32 + UNIT_INDEX;
+    if !(0..=max_index).contains(&index) {
+        return format_with_exponent(x, precision);
+    }
+    let mut scaled = x / 1000f64.powi(index - UNIT_INDEX);
+
+    // Rounding can push the scaled value up to the next prefix, e.g. 999.99 -> 1000.0 -> 1k
+    let factor = 10f64.powi(precision as i32);
+    if (scaled.abs() * factor).round() / factor >= 1000.0 {
+        if index == max_index {
+            return format_with_exponent(x, precision);
+        }
+        index += 1;
+        scaled /= 1000.0;
+    }
+
+    let mut result = format!("{scaled:.precision$}");
+    if result.contains('.') {
+        let trimmed = result.trim_end_matches('0').trim_end_matches('.').len();
+        result.truncate(trimmed);
+    }
+    if result == "-0" {
+        result.remove(0);
+    }
+
+    result.push_str(PREFIXES[index as usize]);
+    result
+}
+
+// Formats a number with an exponent, with trailing zeros removed from the mantissa
+fn format_with_exponent(x: f64, precision: usize) -> String {
+    let result = format!("{x:.precision$e}");
+    match result.split_once('e') {
+        Some((mantissa, exponent)) if mantissa.contains('.') => {
+            let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+            format!("{mantissa}e{exponent}")
+        }
+        _ => result,
+    }
+}
+
+// Spells out an integer in English words, e.g. 21 -> twenty-one
+fn integer_to_words(n: i64) -> String {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    const SCALES: [&str; 7] = [
+        "",
+        "thousand",
+        "million",
+        "billion",
+        "trillion",
+        "quadrillion",
+        "quintillion",
+    ];
+
+    // Spells out a number in the range 1..1000
+    fn hundreds_to_words(n: u64, words: &mut Vec<String>) {
+        let (hundreds, rest) = (n / 100, n % 100);
+        if hundreds > 0 {
+            words.push(format!("{} hundred", ONES[hundreds as usize]));
+        }
+        match rest {
+            0 => {}
+            1..=19 => words.push(ONES[rest as usize].into()),
+            _ => match (TENS[(rest / 10) as usize], rest % 10) {
+                (tens, 0) => words.push(tens.into()),
+                (tens, ones) => words.push(format!("{tens}-{}", ONES[ones as usize])),
+            },
+        }
+    }
+
+    if n == 0 {
+        return ONES[0].into();
+    }
+
+    let mut groups = Vec::new();
+    let mut remaining = n.unsigned_abs();
+    while remaining > 0 {
+        groups.push(remaining % 1000);
+        remaining /= 1000;
+    }
+
+    let mut words = Vec::new();
+    if n < 0 {
+        words.push("minus".into());
+    }
+    for (scale, group) in groups.iter().enumerate().rev() {
+        if *group > 0 {
+            hundreds_to_words(*group, &mut words);
+            if scale > 0 {
+                words.push(SCALES[scale].into());
+            }
+        }
+    }
+
+    words.join(" ")
+}
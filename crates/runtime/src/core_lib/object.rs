@@ -0,0 +1,26 @@
+//! The `object` core library module
+//!
+//! The module's functions are used as a fallback for lookups on objects, when the object doesn't
+//! provide a matching entry itself.
+
+use crate::prelude::*;
+
+/// Initializes the `object` core library module
+pub fn make_module() -> KMap {
+    let result = KMap::with_type("core.object");
+
+    result.add_fn("is_empty", |ctx| {
+        let expected_error = "an Object";
+
+        match ctx.instance_and_args(is_object, expected_error)? {
+            (KValue::Object(o), []) => Ok(o.try_borrow()?.is_empty()?.into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result
+}
+
+fn is_object(value: &KValue) -> bool {
+    matches!(value, KValue::Object(_))
+}
@@ -3,7 +3,7 @@
 pub mod format;
 pub mod iterators;
 
-use super::iterator::collect_pair;
+use super::iterator::{collect_pair, iter_output_to_result};
 use crate::prelude::*;
 use std::convert::TryFrom;
 use unicode_segmentation::UnicodeSegmentation;
@@ -12,6 +12,15 @@ use unicode_segmentation::UnicodeSegmentation;
 pub fn make_module() -> KMap {
     let result = KMap::with_type("core.string");
 
+    result.add_fn("byte_len", |ctx| {
+        let expected_error = "a String";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), []) => Ok(s.len().into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("bytes", |ctx| {
         let expected_error = "a String";
 
@@ -24,6 +33,15 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("char_len", |ctx| {
+        let expected_error = "a String";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), []) => Ok(s.chars().count().into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("chars", |ctx| {
         let expected_error = "a String";
 
@@ -33,6 +51,41 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("common_prefix", |ctx| {
+        let expected_error = "two Strings";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(a), [KValue::Str(b)]) => {
+                let end: usize = a
+                    .graphemes(true)
+                    .zip(b.graphemes(true))
+                    .take_while(|(a, b)| a == b)
+                    .map(|(a, _)| a.len())
+                    .sum();
+                Ok(a.with_bounds(0..end).unwrap().into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("common_suffix", |ctx| {
+        let expected_error = "two Strings";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(a), [KValue::Str(b)]) => {
+                let suffix_len: usize = a
+                    .graphemes(true)
+                    .rev()
+                    .zip(b.graphemes(true).rev())
+                    .take_while(|(a, b)| a == b)
+                    .map(|(a, _)| a.len())
+                    .sum();
+                Ok(a.with_bounds(a.len() - suffix_len..a.len()).unwrap().into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("contains", |ctx| {
         let expected_error = "a String";
 
@@ -62,6 +115,45 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("expand_tabs", |ctx| {
+        let expected_error = "a String and a tab width";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), [KValue::Number(width)]) => {
+                if *width < 1 {
+                    return runtime_error!(
+                        "string.expand_tabs: The tab width must be greater than zero"
+                    );
+                }
+
+                let width: usize = width.into();
+                let mut result = String::with_capacity(s.len());
+                let mut column = 0;
+
+                for grapheme in s.graphemes(true) {
+                    match grapheme {
+                        "\t" => {
+                            let spaces = width - column % width;
+                            result.extend(std::iter::repeat_n(' ', spaces));
+                            column += spaces;
+                        }
+                        "\n" | "\r\n" => {
+                            result.push_str(grapheme);
+                            column = 0;
+                        }
+                        _ => {
+                            result.push_str(grapheme);
+                            column += 1;
+                        }
+                    }
+                }
+
+                Ok(result.into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("format", |ctx| {
         let expected_error = "a String optionally followed by additional values";
 
@@ -79,6 +171,19 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("format_map", |ctx| {
+        let expected_error = "a String and a Map";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(format), [KValue::Map(map)]) => {
+                let format = format.clone();
+                let map = map.clone();
+                format::format_string_with_map(ctx.vm, &format, &map).map(KValue::from)
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("from_bytes", |ctx| match ctx.args() {
         [iterable] if iterable.is_iterable() => {
             let iterable = iterable.clone();
@@ -107,6 +212,24 @@ pub fn make_module() -> KMap {
         unexpected => type_error_with_slice("an iterable", unexpected),
     });
 
+    result.add_fn("grapheme_len", |ctx| {
+        let expected_error = "a String";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), []) => Ok(s.graphemes(true).count().into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("is_ascii", |ctx| {
+        let expected_error = "a String";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), []) => Ok(s.is_ascii().into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("is_empty", |ctx| {
         let expected_error = "a String";
 
@@ -116,6 +239,26 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("levenshtein", |ctx| {
+        let expected_error = "two Strings";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(a), [KValue::Str(b)]) => Ok(levenshtein_distance(a, b).into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("line_at", |ctx| {
+        let expected_error = "a String and a non-negative Number";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), [KValue::Number(n)]) if *n >= 0.0 => {
+                iter_output_to_result(iterators::Lines::new(s.clone()).nth(n.into()))
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("lines", |ctx| {
         let expected_error = "a String";
 
@@ -128,6 +271,59 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("lines_enumerate", |ctx| {
+        let expected_error = "a String";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), []) => {
+                let result = iterators::LinesEnumerate::new(s.clone());
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("mask", |ctx| {
+        let expected_error = "a String, the number of visible graphemes at the start and end, \
+             and an optional mask String";
+
+        let (s, visible_start, visible_end, mask) = match ctx
+            .instance_and_args(is_string, expected_error)?
+        {
+            (KValue::Str(s), [KValue::Number(start), KValue::Number(end)]) => (s, start, end, "*"),
+            (KValue::Str(s), [KValue::Number(start), KValue::Number(end), KValue::Str(mask)]) => {
+                (s, start, end, mask.as_str())
+            }
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        if *visible_start < 0 || *visible_end < 0 {
+            return runtime_error!(
+                "string.mask: The number of visible graphemes must not be negative"
+            );
+        }
+
+        let visible_start: usize = visible_start.into();
+        let visible_end: usize = visible_end.into();
+        let grapheme_count = s.graphemes(true).count();
+
+        if visible_start.saturating_add(visible_end) >= grapheme_count {
+            return Ok(s.clone().into());
+        }
+
+        let masked_count = grapheme_count - visible_start - visible_end;
+        let mut result = String::with_capacity(s.len());
+        for (i, grapheme) in s.graphemes(true).enumerate() {
+            if i < visible_start || i >= visible_start + masked_count {
+                result.push_str(grapheme);
+            } else {
+                result.push_str(mask);
+            }
+        }
+
+        Ok(result.into())
+    });
+
     result.add_fn("replace", |ctx| {
         let expected_error = "a String, followed by pattern and replacement Strings";
 
@@ -139,6 +335,57 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("replace_with", |ctx| {
+        let expected_error = "a String, followed by a pattern String and a replacement function";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(input), [KValue::Str(pattern), f]) if f.is_callable() => {
+                let input = input.clone();
+                let pattern = pattern.clone();
+                let f = f.clone();
+
+                let mut result = String::with_capacity(input.len());
+                let mut last_end = 0;
+
+                for (start, matched) in input.match_indices(pattern.as_str()) {
+                    let end = start + matched.len();
+                    let matched = input.with_bounds(start..end).unwrap();
+
+                    match ctx
+                        .vm
+                        .run_function(f.clone(), CallArgs::Single(matched.into()))?
+                    {
+                        KValue::Str(replacement) => {
+                            result.push_str(&input[last_end..start]);
+                            result.push_str(&replacement);
+                        }
+                        unexpected => {
+                            return type_error(
+                                "a String to be returned from the replacement function",
+                                &unexpected,
+                            )
+                        }
+                    }
+
+                    last_end = end;
+                }
+
+                result.push_str(&input[last_end..]);
+                Ok(result.into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("reverse", |ctx| {
+        let expected_error = "a String";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), []) => Ok(s.graphemes(true).rev().collect::<String>().into()),
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("size", |ctx| {
         let expected_error = "a String";
 
@@ -148,6 +395,42 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("slice", |ctx| {
+        let expected_error = "a String, a start index, and an optional end index";
+
+        let (s, start, end) = match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), [KValue::Number(start)]) => (s, start, None),
+            (KValue::Str(s), [KValue::Number(start), KValue::Number(end)]) => (s, start, Some(end)),
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        // The byte offsets of each grapheme boundary, including the end of the string
+        let boundaries: Vec<usize> = s
+            .grapheme_indices(true)
+            .map(|(i, _)| i)
+            .chain(std::iter::once(s.len()))
+            .collect();
+        let grapheme_count = boundaries.len() - 1;
+
+        // Negative indices count back from the end of the string
+        let resolve_index = |n: &KNumber| {
+            let n = i64::from(n);
+            let index = if n < 0 {
+                (grapheme_count as i64 + n).max(0)
+            } else {
+                n
+            };
+            (index as usize).min(grapheme_count)
+        };
+
+        let start = resolve_index(start);
+        let end = end.map_or(grapheme_count, resolve_index).max(start);
+
+        Ok(s.with_bounds(boundaries[start]..boundaries[end])
+            .unwrap()
+            .into())
+    });
+
     result.add_fn("split", |ctx| {
         let iterator = {
             let expected_error = "a String, and either a String or a predicate function";
@@ -172,6 +455,83 @@ pub fn make_module() -> KMap {
         Ok(KValue::Iterator(iterator))
     });
 
+    result.add_fn("split_at_indices", |ctx| {
+        let expected_error = "a String and a List of indices";
+
+        let (s, indices) = match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), [KValue::List(indices)]) => (s, indices.data().clone()),
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        // The byte offsets of each grapheme boundary, including the end of the string
+        let boundaries: Vec<usize> = s
+            .grapheme_indices(true)
+            .map(|(i, _)| i)
+            .chain(std::iter::once(s.len()))
+            .collect();
+        let grapheme_count = boundaries.len() - 1;
+
+        let mut result = ValueVec::with_capacity(indices.len() + 1);
+        let mut start = 0;
+        for index in indices.iter() {
+            let index = match index {
+                KValue::Number(n) if *n >= 0 && usize::from(n) <= grapheme_count => usize::from(n),
+                KValue::Number(n) => {
+                    return runtime_error!(
+                        "string.split_at_indices: The index {n} is out of range \
+                         (the string has {grapheme_count} graphemes)"
+                    )
+                }
+                unexpected => return type_error("a Number", unexpected),
+            };
+
+            if index < start {
+                return runtime_error!(
+                    "string.split_at_indices: The indices must be in ascending order"
+                );
+            }
+
+            result.push(
+                s.with_bounds(boundaries[start]..boundaries[index])
+                    .unwrap()
+                    .into(),
+            );
+            start = index;
+        }
+        result.push(s.with_bounds(boundaries[start]..s.len()).unwrap().into());
+
+        Ok(KValue::List(KList::with_data(result)))
+    });
+
+    result.add_fn("split_keep_delimiter", |ctx| {
+        let expected_error = "two Strings";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(input), [KValue::Str(pattern)]) => {
+                let result = iterators::SplitKeepDelimiter::new(input.clone(), pattern.clone());
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
+    result.add_fn("split_lines", |ctx| {
+        let expected_error = "a String";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), []) => {
+                let result = iterators::Lines::new(s.clone())
+                    .map(|output| match output {
+                        KIteratorOutput::Value(line) => line,
+                        _ => unreachable!(), // Lines only yields single values
+                    })
+                    .collect::<ValueVec>();
+                Ok(KList::with_data(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("starts_with", |ctx| {
         let expected_error = "two Strings";
 
@@ -183,6 +543,33 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("to_ascii", |ctx| {
+        let expected_error = "a String, and an optional replacement String";
+
+        let (s, replacement) = match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(s), []) => (s, ""),
+            (KValue::Str(s), [KValue::Str(replacement)]) => (s, replacement.as_str()),
+            (_, unexpected) => return type_error_with_slice(expected_error, unexpected),
+        };
+
+        if s.is_ascii() {
+            return Ok(s.clone().into());
+        }
+
+        let result = s
+            .graphemes(true)
+            .map(|grapheme| {
+                if grapheme.is_ascii() {
+                    grapheme
+                } else {
+                    replacement
+                }
+            })
+            .collect::<String>();
+
+        Ok(result.into())
+    });
+
     result.add_fn("to_lowercase", |ctx| {
         let expected_error = "a String";
 
@@ -246,6 +633,18 @@ pub fn make_module() -> KMap {
         }
     });
 
+    result.add_fn("tokenize", |ctx| {
+        let expected_error = "a String, and a String containing delimiter characters";
+
+        match ctx.instance_and_args(is_string, expected_error)? {
+            (KValue::Str(input), [KValue::Str(delimiters)]) => {
+                let result = iterators::Tokenize::new(input.clone(), delimiters.clone());
+                Ok(KIterator::new(result).into())
+            }
+            (_, unexpected) => type_error_with_slice(expected_error, unexpected),
+        }
+    });
+
     result.add_fn("trim", |ctx| {
         let expected_error = "a String";
 
@@ -271,3 +670,33 @@ pub fn make_module() -> KMap {
 fn is_string(value: &KValue) -> bool {
     matches!(value, KValue::Str(_))
 }
+
+// Returns the Levenshtein edit distance between two strings, comparing graphemes
+//
+// Only two rows of the distance matrix are kept in memory at a time.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<&str> = a.graphemes(true).collect();
+    let b: Vec<&str> = b.graphemes(true).collect();
+
+    if a.is_empty() {
+        return b.len();
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_grapheme) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b_grapheme) in b.iter().enumerate() {
+            let substitution_cost = if a_grapheme == b_grapheme { 0 } else { 1 };
+            current[j + 1] = (previous[j] + substitution_cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
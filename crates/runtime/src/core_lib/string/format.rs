@@ -1,8 +1,8 @@
-//! String formatting support for `string.format` and `io.print`
+//! String formatting support for `string.format`, `string.format_map`, and `io.print`
 
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::{runtime_error, KValue, KotoVm, UnaryOp};
+use crate::{runtime_error, KMap, KValue, KotoVm, UnaryOp};
 use koto_lexer::{is_id_continue, is_id_start};
 use std::{iter::Peekable, str::Chars};
 
@@ -334,6 +334,35 @@ pub fn format_string(
     Ok(result)
 }
 
+/// Formats a string using named placeholders that refer to entries in a map,
+/// used by `string.format_map`
+pub fn format_string_with_map(
+    vm: &mut KotoVm,
+    format_string: &str,
+    map: &KMap,
+) -> crate::Result<String> {
+    let mut result = String::with_capacity(format_string.len());
+
+    for token in FormatLexer::new(format_string) {
+        match token {
+            FormatToken::String(s) => result.push_str(s),
+            FormatToken::Identifier(id, format_spec) => {
+                let value = map.data().get(id).cloned();
+                match value {
+                    Some(value) => result.push_str(&value_to_string(vm, &value, format_spec)?),
+                    None => return runtime_error!("Key '{id}' not found in map"),
+                }
+            }
+            FormatToken::Placeholder(_) | FormatToken::Positional(_, _) => {
+                return runtime_error!("Only named placeholders are supported with a map")
+            }
+            FormatToken::Error(error) => return runtime_error!("Invalid format string: {error}"),
+        }
+    }
+
+    Ok(result)
+}
+
 fn value_to_string(
     vm: &mut KotoVm,
     value: &KValue,
@@ -415,7 +444,7 @@ fn value_to_string(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{KMap, ValueMap};
+    use crate::ValueMap;
 
     fn spec_with_precision(precision: u32) -> FormatSpec {
         FormatSpec {
@@ -584,6 +613,27 @@ mod tests {
             check_format_output("{x:.2} - {y:.1}", &[map], "42.00 - -1.0");
         }
 
+        #[test]
+        fn named_placeholders_with_map() {
+            let mut map_data = ValueMap::default();
+            map_data.insert("x".into(), KValue::Number(42.into()));
+            map_data.insert("y".into(), KValue::Str("abc".into()));
+            let map = KMap::with_data(map_data);
+
+            let mut vm = KotoVm::default();
+            let check = |vm: &mut KotoVm, format: &str, expected: &str| match format_string_with_map(
+                vm, format, &map,
+            ) {
+                Ok(result) => assert_eq!(result, expected),
+                Err(error) => panic!("format_string_with_map failed: '{error}'"),
+            };
+
+            check(&mut vm, "{x} - {y}", "42 - abc");
+            check(&mut vm, "{{{x:.1}}}", "{42.0}");
+            assert!(format_string_with_map(&mut vm, "{z}", &map).is_err());
+            assert!(format_string_with_map(&mut vm, "{}", &map).is_err());
+        }
+
         #[test]
         fn fill_and_align_string() {
             let s = &[KValue::Str("abcd".into())];
@@ -103,6 +103,100 @@ impl Iterator for Lines {
     }
 }
 
+/// An iterator that outputs the individual lines from a string, paired with 1-based line numbers
+#[derive(Clone)]
+pub struct LinesEnumerate {
+    lines: Lines,
+    line_number: i64,
+}
+
+impl LinesEnumerate {
+    /// Creates a new [LinesEnumerate] iterator
+    pub fn new(input: KString) -> Self {
+        Self {
+            lines: Lines::new(input),
+            line_number: 0,
+        }
+    }
+}
+
+impl KotoIterator for LinesEnumerate {
+    fn make_copy(&self) -> Result<KIterator> {
+        Ok(KIterator::new(self.clone()))
+    }
+}
+
+impl Iterator for LinesEnumerate {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.lines.next()? {
+            Output::Value(line) => {
+                self.line_number += 1;
+                Some(Output::ValuePair(self.line_number.into(), line))
+            }
+            other => Some(other),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+/// An iterator that splits up a string into tokens, separated by any of a set of delimiter chars
+///
+/// Empty tokens are skipped.
+#[derive(Clone)]
+pub struct Tokenize {
+    input: KString,
+    delimiters: KString,
+    start: usize,
+}
+
+impl Tokenize {
+    /// Creates a new [Tokenize] iterator
+    pub fn new(input: KString, delimiters: KString) -> Self {
+        Self {
+            input,
+            delimiters,
+            start: 0,
+        }
+    }
+
+    fn is_delimiter(&self, c: char) -> bool {
+        self.delimiters.contains(c)
+    }
+}
+
+impl KotoIterator for Tokenize {
+    fn make_copy(&self) -> Result<KIterator> {
+        Ok(KIterator::new(self.clone()))
+    }
+}
+
+impl Iterator for Tokenize {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = &self.input[self.start..];
+        let start = self.start + remaining.find(|c| !self.is_delimiter(c))?;
+        let end = match self.input[start..].find(|c| self.is_delimiter(c)) {
+            Some(end) => start + end,
+            None => self.input.len(),
+        };
+
+        let output = KValue::Str(self.input.with_bounds(start..end).unwrap());
+        self.start = end;
+        Some(Output::Value(output))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining_bytes = self.input.len() - self.start;
+        (0, Some(remaining_bytes))
+    }
+}
+
 /// An iterator that splits up a string into parts, separated by a provided pattern
 #[derive(Clone)]
 pub struct Split {
@@ -153,6 +247,85 @@ impl Iterator for Split {
     }
 }
 
+/// An iterator that splits up a string into parts, yielding the matched delimiters as separate parts
+#[derive(Clone)]
+pub struct SplitKeepDelimiter {
+    input: KString,
+    pattern: KString,
+    start: usize,
+    // The start of a delimiter that should be yielded next
+    pending_delimiter: Option<usize>,
+}
+
+impl SplitKeepDelimiter {
+    /// Creates a new [SplitKeepDelimiter] iterator
+    pub fn new(input: KString, pattern: KString) -> Self {
+        Self {
+            input,
+            pattern,
+            start: 0,
+            pending_delimiter: None,
+        }
+    }
+}
+
+impl KotoIterator for SplitKeepDelimiter {
+    fn make_copy(&self) -> Result<KIterator> {
+        Ok(KIterator::new(self.clone()))
+    }
+}
+
+impl Iterator for SplitKeepDelimiter {
+    type Item = Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(delimiter_start) = self.pending_delimiter.take() {
+            let delimiter_end = delimiter_start + self.pattern.len();
+            let output = KValue::Str(
+                self.input
+                    .with_bounds(delimiter_start..delimiter_end)
+                    .unwrap(),
+            );
+            self.start = delimiter_end;
+            return Some(Output::Value(output));
+        }
+
+        let start = self.start;
+        if start <= self.input.len() {
+            let found = if self.pattern.is_empty() {
+                None
+            } else {
+                self.input[start..].find(self.pattern.as_str())
+            };
+
+            let end = match found {
+                Some(end) => {
+                    let end = start + end;
+                    self.pending_delimiter = Some(end);
+                    end
+                }
+                None => {
+                    self.start = self.input.len() + 1;
+                    self.input.len()
+                }
+            };
+
+            let output = KValue::Str(self.input.with_bounds(start..end).unwrap());
+            Some(Output::Value(output))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.pending_delimiter.is_some() || self.start <= self.input.len() {
+            (1, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
 /// An iterator that splits up a string into parts, separated when a char passes a predicate
 pub struct SplitWith {
     input: KString,
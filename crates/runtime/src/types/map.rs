@@ -53,6 +53,11 @@ impl FromIterator<(ValueKey, KValue)> for ValueMap {
 pub struct KMap {
     data: PtrMut<ValueMap>,
     meta: Option<PtrMut<MetaMap>>,
+    // True when the map has been frozen with `map.freeze`
+    frozen: bool,
+    // True when the map's meta map was added by the map module (e.g. via `map.with_default`),
+    // and the map should otherwise keep behaving like a regular map.
+    map_module_fallback: bool,
 }
 
 impl KMap {
@@ -83,6 +88,7 @@ impl KMap {
         Self {
             data: data.into(),
             meta: meta.map(PtrMut::from),
+            ..Default::default()
         }
     }
 
@@ -91,6 +97,7 @@ impl KMap {
         Self {
             data: data.data.clone(),
             meta: meta.meta.clone(),
+            ..Default::default()
         }
     }
 
@@ -114,6 +121,29 @@ impl KMap {
     /// Sets the KMap's meta map
     pub fn set_meta_map(&mut self, meta: Option<PtrMut<MetaMap>>) {
         self.meta = meta;
+        self.map_module_fallback = false;
+    }
+
+    /// Returns true if the map has been frozen with `map.freeze`
+    pub(crate) fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Marks the map as frozen, causing attempts to modify the map's data to throw errors
+    pub(crate) fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
+    /// Returns true if the map should behave like a regular map, falling back to the map module
+    ///
+    /// This is always the case for maps without a meta map.
+    pub(crate) fn has_map_module_fallback(&self) -> bool {
+        self.meta.is_none() || self.map_module_fallback
+    }
+
+    /// Sets whether or not the map should behave like a regular map despite having a meta map
+    pub(crate) fn set_map_module_fallback(&mut self, fallback: bool) {
+        self.map_module_fallback = fallback;
     }
 
     /// Returns true if the meta map contains an entry with the given key
@@ -216,6 +216,14 @@ pub trait KotoObject: KotoType + KotoCopy + KotoLookup + KotoSend + KotoSync + D
         unimplemented_error("@!=", self.type_string())
     }
 
+    /// Returns true if the object is empty, e.g. when calling `x.is_empty()`
+    ///
+    /// This is only called when the object's [lookup](KotoLookup::lookup) doesn't provide an
+    /// `is_empty` entry.
+    fn is_empty(&self) -> Result<bool> {
+        unimplemented_error("is_empty", self.type_string())
+    }
+
     /// Declares to the runtime whether or not the object is iterable
     fn is_iterable(&self) -> IsIterable {
         IsIterable::NotIterable
@@ -108,6 +108,16 @@ impl KTuple {
         }
     }
 
+    /// Returns a mutable reference to the tuple's values, if its data isn't shared
+    ///
+    /// This allows a tuple's allocation to be reused when there are no other references to it.
+    pub fn data_mut(&mut self) -> Option<&mut [KValue]> {
+        match &mut self.0 {
+            Inner::Full(data) => Ptr::get_mut(data),
+            Inner::Slice(_) => None,
+        }
+    }
+
     /// Renders the tuple into the provided display context
     pub fn display(&self, ctx: &mut DisplayContext) -> Result<()> {
         let id = Ptr::address(match &self.0 {
@@ -83,7 +83,10 @@ impl KValue {
                     .map(|(k, v)| v.deep_copy().map(|v| (k.clone(), v)))
                     .collect::<Result<_>>()?;
                 let meta = m.meta_map().map(|meta| meta.borrow().clone());
-                KMap::with_contents(data, meta).into()
+                let mut result = KMap::with_contents(data, meta);
+                result.set_frozen(m.is_frozen());
+                result.set_map_module_fallback(m.has_map_module_fallback());
+                result.into()
             }
             KValue::Iterator(i) => i.make_copy()?.into(),
             KValue::Object(o) => o.try_borrow()?.copy().into(),
@@ -172,7 +175,7 @@ impl KValue {
             Number(KNumber::I64(_)) => TYPE_INT.with(|x| x.clone()),
             List(_) => TYPE_LIST.with(|x| x.clone()),
             Range { .. } => TYPE_RANGE.with(|x| x.clone()),
-            Map(m) if m.meta_map().is_some() => match m.get_meta_value(&MetaKey::Type) {
+            Map(m) if !m.has_map_module_fallback() => match m.get_meta_value(&MetaKey::Type) {
                 Some(Str(s)) => s,
                 Some(_) => "Error: expected string for overloaded type".into(),
                 None => TYPE_OBJECT.with(|x| x.clone()),
@@ -2232,6 +2232,9 @@ impl KotoVm {
         let value = self.clone_register(value_register);
 
         match self.get_register_mut(map_register) {
+            KValue::Map(map) if map.is_frozen() => {
+                runtime_error!("Unable to assign '{key}' to a frozen map")
+            }
             KValue::Map(map) => {
                 map.data_mut().insert(key, value);
                 Ok(())
@@ -2365,6 +2368,11 @@ impl KotoVm {
                                 Some(unexpected) => {
                                     return type_error("Map as base value", &unexpected)
                                 }
+                                // Fallback to the map module for views like `map.with_default`
+                                None if map.has_map_module_fallback() => {
+                                    core_op!(map, true);
+                                    return Ok(());
+                                }
                                 None => break,
                             },
                         },
@@ -2399,16 +2407,21 @@ impl KotoVm {
                     self.set_register(result_register, value);
                 } else {
                     // Iterator fallback?
-                    if !matches!(o.is_iterable(), IsIterable::NotIterable) {
-                        let iterator_op = self.get_core_op(
-                            &key,
-                            &self.context.core_lib.iterator,
-                            false,
-                            &accessed_value.type_as_string(),
-                        )?;
-                        self.set_register(result_register, iterator_op);
+                    let iterator_op = if !matches!(o.is_iterable(), IsIterable::NotIterable) {
+                        self.context.core_lib.iterator.data().get(&key).cloned()
                     } else {
-                        return runtime_error!("'{key}' not found in '{}'", o.type_string());
+                        None
+                    };
+
+                    // Object module fallback?
+                    let op = iterator_op
+                        .or_else(|| self.context.core_lib.object.data().get(&key).cloned());
+
+                    match op {
+                        Some(op) => self.set_register(result_register, op),
+                        None => {
+                            return runtime_error!("'{key}' not found in '{}'", o.type_string())
+                        }
                     }
                 }
             }
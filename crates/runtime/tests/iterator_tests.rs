@@ -6,6 +6,23 @@ use koto_runtime::KValue;
 mod iterator {
     use super::*;
 
+    mod buffered {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).buffered 3
+x.next() # 1
+y = copy x
+x.next() # 2
+x.next() # 3
+y.next()
+";
+            test_script(script, 2);
+        }
+    }
+
     mod chain {
         use super::*;
 
@@ -38,6 +55,41 @@ y.next()
         }
     }
 
+    mod chain_many {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (0..2).chain_many 2..3, 3..5
+x.next() # 0
+x.next() # 1
+y = copy x
+x.next() # 2
+x.next() # 3
+y.next()
+";
+            test_script(script, 2);
+        }
+    }
+
+    mod chunk_sum {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).chunk_sum 2
+x.next() # 3
+y = copy x
+x.next() # 7
+x.next() # 11
+y.next()
+";
+            test_script(script, 7);
+        }
+    }
+
     mod chunks {
         use super::*;
 
@@ -76,6 +128,61 @@ generator()
         }
     }
 
+    mod chunks_overlapping {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).chunks_overlapping 3, 1
+x.next() # 1, 2, 3
+y = copy x
+x.next() # 3, 4, 5
+x.next() # 5, 6, 7
+y.next()
+";
+            test_script(script, number_tuple(&[3, 4, 5]));
+        }
+
+        #[test]
+        fn with_generator() {
+            let script = "
+generator = ||
+  for i in 1..=5
+    yield i
+generator()
+  .chunks_overlapping 3, 2
+  .to_tuple()
+";
+            test_script(
+                script,
+                tuple(&[
+                    number_tuple(&[1, 2, 3]),
+                    number_tuple(&[2, 3, 4]),
+                    number_tuple(&[3, 4, 5]),
+                ]),
+            );
+        }
+    }
+
+    mod cumulative_counts {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 2, 1, 1, 2).cumulative_counts()
+x.next() # 1, 1
+x.next() # 2, 1
+y = copy x
+x.next() # 1, 2
+x.next() # 1, 3
+y.next()[1]
+";
+            test_script(script, 2);
+        }
+    }
+
     mod cycle {
         use super::*;
 
@@ -141,6 +248,40 @@ x.next()
         }
     }
 
+    mod dedup {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 1, 2, 2, 3, 3, 4).dedup()
+x.next() # 1
+y = copy x
+x.next() # 2
+x.next() # 3
+y.next()
+";
+            test_script(script, 2);
+        }
+    }
+
+    mod dedup_map {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 1, 2, 2, 3, 3, 4).dedup_map |n| n * 10
+x.next() # 10
+y = copy x
+x.next() # 20
+x.next() # 30
+y.next()
+";
+            test_script(script, 20);
+        }
+    }
+
     mod enumerate {
         use super::*;
 
@@ -158,6 +299,128 @@ y.next()
         }
     }
 
+    mod enumerate_filter {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (10..20).enumerate_filter |n| n % 3 == 0
+x.next() # 2, 12
+y = copy x
+x.next() # 5, 15
+x.next() # 8, 18
+y.next()
+";
+            test_script(script, tuple(&[5.into(), 15.into()]));
+        }
+    }
+
+    mod enumerate_with_total {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (10..20).enumerate_with_total()
+x.next() # 0, 10, 10
+y = copy x
+x.next() # 1, 10, 11
+x.next() # 2, 10, 12
+y.next()
+";
+            test_script(script, tuple(&[1.into(), 10.into(), 11.into()]));
+        }
+    }
+
+    mod flat_map {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 2, 3).flat_map |n| 0..n
+x.next() # 0
+x.next() # 0
+y = copy x
+x.next() # 1
+x.next() # 0
+y.next()
+";
+            test_script(script, 1);
+        }
+    }
+
+    mod flat_map_indexed {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 2, 3).flat_map_indexed |i, x| (i, x)
+x.next() # 0
+x.next() # 1
+x.next() # 1
+y = copy x
+x.next() # 2
+y.next()
+";
+            test_script(script, 2);
+        }
+    }
+
+    mod flat_map_pairs {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 2, 3).flat_map_pairs |x| [(x, x * 10), (x, x * 100)]
+x.next() # (1, 10)
+x.next() # (1, 100)
+x.next() # (2, 20)
+y = copy x
+x.next() # (2, 200)
+y.next()
+";
+            test_script(script, number_tuple(&[2, 200]));
+        }
+    }
+
+    mod group_adjacent_sum {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 3, 2, 4, 5, 7).group_adjacent_sum (|n| n % 2), (|a, b| a + b)
+x.next() # 4
+y = copy x
+x.next() # 6
+x.next() # 12
+y.next()
+";
+            test_script(script, 6);
+        }
+    }
+
+    mod group_by {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 3, 2, 4, 5, 7).group_by |n| n % 2
+x.next() # (1, [1, 3])
+y = copy x
+x.next() # (0, [2, 4])
+x.next() # (1, [5, 7])
+y.next()[1].size()
+";
+            test_script(script, 2);
+        }
+    }
+
     mod intersperse {
         use super::*;
 
@@ -190,6 +453,23 @@ y.next()
         }
     }
 
+    mod intersperse_after {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 2, 3).intersperse_after 0
+x.next() # 1
+y = copy x
+x.next() # 0
+x.next() # 2
+y.next()
+";
+            test_script(script, 0);
+        }
+    }
+
     mod keep {
         use super::*;
 
@@ -206,6 +486,23 @@ y.next()
         }
     }
 
+    mod pairwise_map {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 3, 6, 10, 15).pairwise_map |a, b| b - a
+x.next() # 2
+y = copy x
+x.next() # 3
+x.next() # 4
+y.next()
+";
+            test_script(script, 3);
+        }
+    }
+
     mod peekable {
         use super::*;
         use KValue::Null;
@@ -299,6 +596,57 @@ result
         }
     }
 
+    mod scan {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).scan 0, |total, n| total + n
+x.next() # 1
+y = copy x
+x.next() # 3
+x.next() # 6
+y.next()
+";
+            test_script(script, 3);
+        }
+    }
+
+    mod scan_map {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).scan_map 0, |total, n| total + n
+x.next() # (1, 1)
+y = copy x
+x.next() # (3, 2)
+x.next() # (6, 3)
+y.next()[0]
+";
+            test_script(script, 3);
+        }
+    }
+
+    mod scan_until {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).scan_until 0, (|total, n| total + n), |total| total > 10
+x.next() # 1
+y = copy x
+x.next() # 3
+x.next() # 6
+y.next()
+";
+            test_script(script, 3);
+        }
+    }
+
     mod skip {
         use super::*;
 
@@ -311,6 +659,40 @@ result
         }
     }
 
+    mod sliding_reduce {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).sliding_reduce 2, 0, |total, window| (total + window.sum(), total + 1)
+x.next() # 1
+x.next() # 4
+y = copy x
+x.next() # 7
+y.next()
+";
+            test_script(script, 7);
+        }
+    }
+
+    mod step_windows {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..=10).step_windows 2, 3
+x.next() # (1, 2)
+y = copy x
+x.next() # (4, 5)
+x.next() # (7, 8)
+y.next()[0]
+";
+            test_script(script, 4);
+        }
+    }
+
     mod take {
         use super::*;
 
@@ -328,6 +710,41 @@ y.next()
         }
     }
 
+    mod take_until {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).take_until |n| n == 3
+x.next() # 1
+y = copy x
+x.next() # 2
+x.next() # 3
+x.next() # null
+y.next()
+";
+            test_script(script, 2);
+        }
+    }
+
+    mod unique {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 1, 2, 1, 3, 2, 4).unique()
+x.next() # 1
+y = copy x
+x.next() # 2
+x.next() # 3
+y.next()
+";
+            test_script(script, 2);
+        }
+    }
+
     mod windows {
         use super::*;
 
@@ -377,6 +794,23 @@ result
         }
     }
 
+    mod windows_stddev {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 3, 7, 13, 21).windows_stddev 2
+x.next() # 1
+y = copy x
+x.next() # 2
+x.next() # 3
+y.next()
+";
+            test_script(script, number(2));
+        }
+    }
+
     mod zip {
         use super::*;
 
@@ -393,6 +827,142 @@ y.next()
             test_script(script, number_tuple(&[3, 13]));
         }
     }
+
+    mod zip3 {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..5).zip3 11..15, 21..25
+x.next() # (1, 11, 21)
+y = copy x
+x.next() # (2, 12, 22)
+x.next() # (3, 13, 23)
+y.next()
+";
+            test_script(script, number_tuple(&[2, 12, 22]));
+        }
+    }
+
+    mod windows_apply {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).windows_apply 2, |(a, b)| a + b
+x.next() # 3
+y = copy x
+x.next() # 5
+x.next() # 7
+y.next()
+";
+            test_script(script, 5);
+        }
+    }
+
+    mod windows_argmax {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 5, 2, 8, 3, 3).windows_argmax 3
+x.next() # 1
+y = copy x
+x.next() # 2
+x.next() # 1
+y.next()
+";
+            test_script(script, 2);
+        }
+    }
+
+    mod windows_dedup {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 1, 1, 2, 2, 2, 3).windows_dedup 2
+x.next() # (1, 1)
+y = copy x
+x.next() # (1, 2)
+x.next() # (2, 2)
+y.next()
+";
+            test_script(script, number_tuple(&[1, 2]));
+        }
+    }
+
+    mod windows_diff {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1, 2, 4, 7, 11).windows_diff 2
+x.next() # [1, 2]
+y = copy x
+x.next() # [2, 3]
+x.next() # [3, 4]
+y.next()[1]
+";
+            test_script(script, 3);
+        }
+    }
+
+    mod windows_join {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).windows_join 2, ','
+x.next() # 1,2
+y = copy x
+x.next() # 2,3
+x.next() # 3,4
+y.next()
+";
+            test_script(script, "2,3");
+        }
+    }
+
+    mod windows_reduce {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..10).windows_reduce 2, 0, |carry, (a, b)| (carry + a + b, carry + 1)
+x.next() # 3
+x.next() # 6
+y = copy x
+x.next() # 9
+y.next()
+";
+            test_script(script, 9);
+        }
+    }
+
+    mod zip_with {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = (1..5).zip_with 11..15, |a, b| a + b
+x.next() # 12
+x.next() # 14
+y = copy x
+x.next() # 16
+y.next()
+";
+            test_script(script, 16);
+        }
+    }
 }
 
 mod map {
@@ -489,6 +1059,39 @@ y.next()
         }
     }
 
+    mod lines_enumerate {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = 'abc\ndef\nxyz'.lines_enumerate()
+x.next() # 1, abc
+y = copy x
+x.next() # 2, def
+y.next()[0]
+";
+            test_script(script, 2);
+        }
+    }
+
+    mod tokenize {
+        use super::*;
+
+        #[test]
+        fn make_copy() {
+            let script = "
+x = 'a, b, c'.tokenize ', '
+x.next() # a
+y = copy x
+x.next() # b
+x.next() # c
+y.next()
+";
+            test_script(script, "b");
+        }
+    }
+
     mod split {
         use super::*;
 
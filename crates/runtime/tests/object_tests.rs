@@ -204,6 +204,10 @@ mod objects {
             comparison_op!(self, rhs, !=)
         }
 
+        fn is_empty(&self) -> Result<bool> {
+            Ok(self.x == 0)
+        }
+
         fn is_iterable(&self) -> IsIterable {
             IsIterable::Iterable
         }
@@ -268,6 +272,24 @@ x.to_number()
             test_object_script(script, 42);
         }
 
+        #[test]
+        fn is_empty_zero() {
+            let script = "
+x = make_object 0
+x.is_empty()
+";
+            test_object_script(script, true);
+        }
+
+        #[test]
+        fn is_empty_non_zero() {
+            let script = "
+x = make_object 42
+x.is_empty()
+";
+            test_object_script(script, false);
+        }
+
         #[test]
         fn invert() {
             let script = "
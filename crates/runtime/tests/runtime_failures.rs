@@ -199,6 +199,33 @@ x.insert {foo: 42}, 'hello'
                 let script = "
 x = {}
 x.insert (1, [2, 3]), 'hello'
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn assign_to_frozen_map() {
+                let script = "
+x = {foo: 42}.freeze()
+x.foo = 99
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn missing_entry_access_with_default() {
+                let script = "
+x = {foo: 42}.with_default 0
+x.bar
+";
+                check_script_fails(script);
+            }
+
+            #[test]
+            fn compound_assign_to_frozen_map() {
+                let script = "
+x = {foo: 42}.freeze()
+x.foo += 1
 ";
                 check_script_fails(script);
             }
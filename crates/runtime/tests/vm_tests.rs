@@ -3259,6 +3259,47 @@ dog('Fido').speak()
         }
     }
 
+    mod map_module_fallback {
+        use super::*;
+
+        #[test]
+        fn data_entry() {
+            let script = "
+m = {foo: 42}.with_default 0
+m.foo
+";
+            test_script(script, 42);
+        }
+
+        #[test]
+        fn map_module_function_without_call() {
+            let script = "
+m = {foo: 42, bar: 99}.with_default 0
+size = m.size
+size m
+";
+            test_script(script, 2);
+        }
+
+        #[test]
+        fn data_entry_before_map_module() {
+            let script = "
+m = {size: 42}.with_default 0
+m.size
+";
+            test_script(script, 42);
+        }
+
+        #[test]
+        fn meta_entry_before_map_module() {
+            let script = "
+m = {foo: 42}.with_default -1
+m.get 'bar'
+";
+            test_script(script, -1);
+        }
+    }
+
     mod import {
         use super::*;
 